@@ -0,0 +1,257 @@
+//! A [`BinaryHeap`] that is guaranteed to contain at least one element.
+
+use alloc::collections::binary_heap::{self, BinaryHeap};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+
+use crate::{EmptyContainerError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty priority queue, ensure non empty by construction.
+/// Inherits `BinaryHeap`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `peek` doesn't return `Option`, and `peek_mut` returns a [`PeekMut`] that can't remove the element.
+/// * `pop` returns `None` if there is only one element in it.
+#[derive(Debug, Clone)]
+pub struct NonEmptyBinaryHeap<T>(BinaryHeap<T>);
+
+impl<T: Ord> NonEmptyBinaryHeap<T> {
+    #[inline]
+    pub fn new(v: T) -> Self {
+        Self(BinaryHeap::from(alloc::vec![v]))
+    }
+
+    /// Constructs a non-empty heap without checking its size.
+    ///
+    /// # Safety
+    /// `heap` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(heap: BinaryHeap<T>) -> Self {
+        debug_assert!(!heap.is_empty());
+        Self(heap)
+    }
+
+    #[inline]
+    pub fn as_binary_heap(&self) -> &BinaryHeap<T> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the greatest element of the heap.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBinaryHeap};
+    /// let heap = NonEmptyBinaryHeap::from(ne_vec![1, 5, 2]);
+    /// assert_eq!(heap.peek(), &5);
+    /// ```
+    #[inline]
+    pub fn peek(&self) -> &T {
+        match self.0.peek() {
+            Some(top) => top,
+            // SAFETY: This heap is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns a mutable reference to the greatest element of the heap.
+    /// The heap is restored when the returned [`PeekMut`] is dropped.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBinaryHeap};
+    /// let mut heap = NonEmptyBinaryHeap::from(ne_vec![1, 5, 2]);
+    /// *heap.peek_mut() = 0;
+    /// assert_eq!(heap.peek(), &2);
+    /// ```
+    #[inline]
+    pub fn peek_mut(&mut self) -> PeekMut<'_, T> {
+        match self.0.peek_mut() {
+            Some(inner) => PeekMut { inner },
+            // SAFETY: This heap is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, v: T) {
+        self.0.push(v)
+    }
+
+    /// Removes the greatest element from the heap and returns it.
+    /// Returns `None` if there is only one element in the heap.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBinaryHeap};
+    /// let mut heap = NonEmptyBinaryHeap::from(ne_vec![1, 3]);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), None);
+    /// assert_eq!(heap.peek(), &1);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop()
+        }
+    }
+
+    /// Consumes the heap and returns a vector sorted in ascending order.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBinaryHeap};
+    /// let mut heap = NonEmptyBinaryHeap::new(3);
+    /// heap.push(1);
+    /// heap.push(2);
+    /// assert_eq!(heap.into_sorted_vec(), ne_vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_sorted_vec(self) -> NonEmpty<T> {
+        // SAFETY: The heap is non-empty.
+        unsafe { NonEmpty::new_unchecked(self.0.into_sorted_vec()) }
+    }
+}
+
+impl<T> NonEmptyBinaryHeap<T> {
+    /// Consumes the heap and returns the underlying vector in arbitrary order.
+    #[inline]
+    pub fn into_vec(self) -> NonEmpty<T> {
+        // SAFETY: The heap is non-empty.
+        unsafe { NonEmpty::new_unchecked(self.0.into_vec()) }
+    }
+}
+
+/// Converts the vector into a heap in place, in linear time.
+impl<T: Ord> From<NonEmpty<T>> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        Self(BinaryHeap::from(Vec::from(v)))
+    }
+}
+
+impl<T> From<NonEmptyBinaryHeap<T>> for NonEmpty<T> {
+    #[inline]
+    fn from(heap: NonEmptyBinaryHeap<T>) -> Self {
+        heap.into_vec()
+    }
+}
+
+impl<T> From<NonEmptyBinaryHeap<T>> for BinaryHeap<T> {
+    #[inline]
+    fn from(heap: NonEmptyBinaryHeap<T>) -> Self {
+        heap.0
+    }
+}
+
+impl<T> TryFrom<BinaryHeap<T>> for NonEmptyBinaryHeap<T> {
+    type Error = EmptyContainerError<BinaryHeap<T>>;
+    fn try_from(heap: BinaryHeap<T>) -> Result<Self, Self::Error> {
+        if heap.is_empty() {
+            Err(EmptyContainerError(heap))
+        } else {
+            Ok(Self(heap))
+        }
+    }
+}
+
+impl<T: Ord> FromNonEmptyIterator<T> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let heap = BinaryHeap::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(heap) }
+    }
+}
+
+impl<T> ops::Deref for NonEmptyBinaryHeap<T> {
+    type Target = BinaryHeap<T>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<BinaryHeap<T>> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn as_ref(&self) -> &BinaryHeap<T> {
+        &self.0
+    }
+}
+
+impl<T: Ord> Extend<T> for NonEmptyBinaryHeap<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyBinaryHeap<T> {
+    type Item = T;
+    type IntoIter = binary_heap::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a NonEmptyBinaryHeap<T> {
+    type Item = &'a T;
+    type IntoIter = binary_heap::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for NonEmptyBinaryHeap<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for NonEmptyBinaryHeap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<BinaryHeap<T>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty heap"))
+    }
+}
+
+/// Mutable reference to the greatest element of a [`NonEmptyBinaryHeap`].
+///
+/// This is created by [`NonEmptyBinaryHeap::peek_mut`]. Unlike [`binary_heap::PeekMut`],
+/// it can't be used to pop the element, since that might leave the heap empty.
+#[derive(Debug)]
+pub struct PeekMut<'a, T: Ord> {
+    inner: binary_heap::PeekMut<'a, T>,
+}
+impl<'a, T: Ord> ops::Deref for PeekMut<'a, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+impl<'a, T: Ord> ops::DerefMut for PeekMut<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,452 @@
+//! A [`BTreeMap`] that is guaranteed to contain at least one entry.
+
+use alloc::collections::btree_map::{self, BTreeMap};
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+
+use crate::{EmptyContainerError, EmptyError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty ordered map, ensure non empty by construction.
+/// Inherits `BTreeMap`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `first_key_value` and `last_key_value` don't return `Option`.
+/// * `pop_first`, `pop_last` and `remove` return `None` if there is only one entry in it.
+/// * `keys`, `values` and `values_mut` return [non-empty iterators](NonEmptyIterator).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBTreeMap<K, V>(BTreeMap<K, V>);
+
+impl<K, V> NonEmptyBTreeMap<K, V> {
+    /// Creates a map containing a single entry.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyBTreeMap;
+    /// let map = NonEmptyBTreeMap::new("a", 1);
+    /// assert_eq!(map.first_key_value(), (&"a", &1));
+    /// ```
+    #[inline]
+    pub fn new(key: K, value: V) -> Self
+    where
+        K: Ord,
+    {
+        let mut map = BTreeMap::new();
+        map.insert(key, value);
+        Self(map)
+    }
+
+    /// Constructs a non-empty map without checking its size.
+    ///
+    /// # Safety
+    /// `map` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(map: BTreeMap<K, V>) -> Self {
+        debug_assert!(!map.is_empty());
+        Self(map)
+    }
+
+    #[inline]
+    pub fn as_btree_map(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the entry with the smallest key.
+    #[inline]
+    pub fn first_key_value(&self) -> (&K, &V) {
+        match self.0.iter().next() {
+            Some(entry) => entry,
+            // SAFETY: This map is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns the entry with the largest key.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBTreeMap};
+    /// let map = NonEmptyBTreeMap::from(ne_vec![(2, 'b'), (1, 'a')]);
+    /// assert_eq!(map.first_key_value(), (&1, &'a'));
+    /// assert_eq!(map.last_key_value(), (&2, &'b'));
+    /// ```
+    #[inline]
+    pub fn last_key_value(&self) -> (&K, &V) {
+        match self.0.iter().next_back() {
+            Some(entry) => entry,
+            // SAFETY: This map is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: ?Sized + Ord,
+    {
+        self.0.get_mut(key)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> btree_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Inserts a key-value pair into the map, returning the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Ord,
+    {
+        self.0.insert(key, value)
+    }
+
+    /// Removes a key from the map, returning its value.
+    /// Returns `None` if the key is not in the map, or if it is the only key in the map.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBTreeMap};
+    /// let mut map = NonEmptyBTreeMap::from(ne_vec![(1, 'a'), (2, 'b')]);
+    /// assert_eq!(map.remove(&3), None);
+    /// assert_eq!(map.remove(&1), Some('a'));
+    /// assert_eq!(map.remove(&2), None);
+    /// assert_eq!(map.first_key_value(), (&2, &'b'));
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q> + Ord,
+        Q: ?Sized + Ord,
+    {
+        self.try_remove(key).ok().flatten()
+    }
+
+    /// Removes a key from the map, returning its value if it was in the map.
+    /// Returns [`EmptyError`] if the key is the only key in the map.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{EmptyError, NonEmptyBTreeMap};
+    /// let mut map = NonEmptyBTreeMap::new(1, 'a');
+    /// assert_eq!(map.try_remove(&2), Ok(None));
+    /// assert_eq!(map.try_remove(&1), Err(EmptyError));
+    /// ```
+    pub fn try_remove<Q>(&mut self, key: &Q) -> Result<Option<V>, EmptyError>
+    where
+        K: Borrow<Q> + Ord,
+        Q: ?Sized + Ord,
+    {
+        // The inner map is never asked to remove the only entry, so that an inconsistent
+        // `Ord` can't make the lookup and the removal disagree and leave the map empty.
+        if self.0.len() > 1 {
+            Ok(self.0.remove(key))
+        } else if self.0.contains_key(key) {
+            Err(EmptyError)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Removes and returns the entry with the smallest key.
+    /// Returns `None` if there is only one entry in the map.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_first()
+        }
+    }
+
+    /// Removes and returns the entry with the largest key.
+    /// Returns `None` if there is only one entry in the map.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)>
+    where
+        K: Ord,
+    {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_last()
+        }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the entries of the map, sorted by key.
+    #[inline]
+    pub fn ne_iter(&self) -> Iter<'_, K, V> {
+        Iter { map: self }
+    }
+
+    /// Creates a [non-empty iterator](NonEmptyIterator) that moves each entry out of the map.
+    #[inline]
+    pub fn into_ne_iter(self) -> IntoIter<K, V> {
+        IntoIter { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the keys of the map, in sorted order.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyBTreeMap, NonEmptyIterator};
+    /// let map = NonEmptyBTreeMap::from(ne_vec![("b", 2), ("a", 1)]);
+    /// let keys: NonEmpty<_> = map.keys().copied().collect();
+    /// assert_eq!(keys, ne_vec!["a", "b"]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the values of the map, in order by key.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over mutable references to the values of the map,
+    /// in order by key.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { map: self }
+    }
+}
+
+/// Collects the pairs into a map. If a key occurs more than once, the last value is kept.
+impl<K: Ord, V> From<NonEmpty<(K, V)>> for NonEmptyBTreeMap<K, V> {
+    #[inline]
+    fn from(v: NonEmpty<(K, V)>) -> Self {
+        Self::from_ne_iter(v.into_ne_iter())
+    }
+}
+
+impl<K, V> From<NonEmptyBTreeMap<K, V>> for BTreeMap<K, V> {
+    #[inline]
+    fn from(map: NonEmptyBTreeMap<K, V>) -> Self {
+        map.0
+    }
+}
+
+impl<K, V> TryFrom<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+    type Error = EmptyContainerError<BTreeMap<K, V>>;
+    fn try_from(map: BTreeMap<K, V>) -> Result<Self, Self::Error> {
+        if map.is_empty() {
+            Err(EmptyContainerError(map))
+        } else {
+            Ok(Self(map))
+        }
+    }
+}
+
+impl<K: Ord, V> FromNonEmptyIterator<(K, V)> for NonEmptyBTreeMap<K, V> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = (K, V)>,
+    {
+        let map = BTreeMap::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(map) }
+    }
+}
+
+impl<K, V> ops::Deref for NonEmptyBTreeMap<K, V> {
+    type Target = BTreeMap<K, V>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V> AsRef<BTreeMap<K, V>> for NonEmptyBTreeMap<K, V> {
+    #[inline]
+    fn as_ref(&self) -> &BTreeMap<K, V> {
+        &self.0
+    }
+}
+
+impl<K, Q, V> ops::Index<&Q> for NonEmptyBTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: ?Sized + Ord,
+{
+    type Output = V;
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        &self.0[key]
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for NonEmptyBTreeMap<K, V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K, V> IntoIterator for NonEmptyBTreeMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, K, V> IntoIterator for &'a NonEmptyBTreeMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = btree_map::Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, K, V> IntoIterator for &'a mut NonEmptyBTreeMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = btree_map::IterMut<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Serialize, V: Serialize> Serialize for NonEmptyBTreeMap<K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Deserialize<'de> for NonEmptyBTreeMap<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<BTreeMap<K, V>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty map"))
+    }
+}
+
+/// Non-empty iterator over the entries of a [`NonEmptyBTreeMap`].
+///
+/// This is created by [`NonEmptyBTreeMap::ne_iter`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, K, V> {
+    map: &'a NonEmptyBTreeMap<K, V>,
+}
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V> IntoIterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = btree_map::Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.iter()
+    }
+}
+unsafe impl<'a, K, V> NonEmptyIterator for Iter<'a, K, V> {}
+
+/// Non-empty iterator that moves the entries out of a [`NonEmptyBTreeMap`].
+///
+/// This is created by [`NonEmptyBTreeMap::into_ne_iter`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<K, V> {
+    map: NonEmptyBTreeMap<K, V>,
+}
+impl<K, V> IntoIterator for IntoIter<K, V> {
+    type Item = (K, V);
+    type IntoIter = btree_map::IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.into_iter()
+    }
+}
+unsafe impl<K, V> NonEmptyIterator for IntoIter<K, V> {}
+
+/// Non-empty iterator over the keys of a [`NonEmptyBTreeMap`].
+///
+/// This is created by [`NonEmptyBTreeMap::keys`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Keys<'a, K, V> {
+    map: &'a NonEmptyBTreeMap<K, V>,
+}
+impl<'a, K, V> Clone for Keys<'a, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V> IntoIterator for Keys<'a, K, V> {
+    type Item = &'a K;
+    type IntoIter = btree_map::Keys<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.keys()
+    }
+}
+unsafe impl<'a, K, V> NonEmptyIterator for Keys<'a, K, V> {}
+
+/// Non-empty iterator over the values of a [`NonEmptyBTreeMap`].
+///
+/// This is created by [`NonEmptyBTreeMap::values`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Values<'a, K, V> {
+    map: &'a NonEmptyBTreeMap<K, V>,
+}
+impl<'a, K, V> Clone for Values<'a, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V> IntoIterator for Values<'a, K, V> {
+    type Item = &'a V;
+    type IntoIter = btree_map::Values<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.values()
+    }
+}
+unsafe impl<'a, K, V> NonEmptyIterator for Values<'a, K, V> {}
+
+/// Non-empty iterator over mutable references to the values of a [`NonEmptyBTreeMap`].
+///
+/// This is created by [`NonEmptyBTreeMap::values_mut`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ValuesMut<'a, K, V> {
+    map: &'a mut NonEmptyBTreeMap<K, V>,
+}
+impl<'a, K, V> IntoIterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+    type IntoIter = btree_map::ValuesMut<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.values_mut()
+    }
+}
+unsafe impl<'a, K, V> NonEmptyIterator for ValuesMut<'a, K, V> {}
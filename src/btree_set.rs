@@ -0,0 +1,306 @@
+//! A [`BTreeSet`] that is guaranteed to contain at least one element.
+
+use alloc::collections::btree_set::{self, BTreeSet};
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+
+use crate::{EmptyContainerError, EmptyError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty ordered set, ensure non empty by construction.
+/// Inherits `BTreeSet`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `first` and `last` don't return `Option`.
+/// * `pop_first`, `pop_last` and `remove` won't remove the only element in it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyBTreeSet<T>(BTreeSet<T>);
+
+impl<T> NonEmptyBTreeSet<T> {
+    /// Creates a set containing a single element.
+    #[inline]
+    pub fn new(value: T) -> Self
+    where
+        T: Ord,
+    {
+        let mut set = BTreeSet::new();
+        set.insert(value);
+        Self(set)
+    }
+
+    /// Constructs a non-empty set without checking its size.
+    ///
+    /// # Safety
+    /// `set` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(set: BTreeSet<T>) -> Self {
+        debug_assert!(!set.is_empty());
+        Self(set)
+    }
+
+    #[inline]
+    pub fn as_btree_set(&self) -> &BTreeSet<T> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the smallest element of the set.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBTreeSet};
+    /// let set = NonEmptyBTreeSet::from(ne_vec![3, 1, 2]);
+    /// assert_eq!(set.first(), &1);
+    /// assert_eq!(set.last(), &3);
+    /// ```
+    #[inline]
+    pub fn first(&self) -> &T {
+        match self.0.iter().next() {
+            Some(first) => first,
+            // SAFETY: This set is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns the largest element of the set.
+    #[inline]
+    pub fn last(&self) -> &T {
+        match self.0.iter().next_back() {
+            Some(last) => last,
+            // SAFETY: This set is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool
+    where
+        T: Ord,
+    {
+        self.0.insert(value)
+    }
+
+    /// Removes a value from the set, returning whether it was removed.
+    /// Returns `false` if the value is not in the set, or if it is the only value in the set.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyBTreeSet};
+    /// let mut set = NonEmptyBTreeSet::from(ne_vec![1, 2]);
+    /// assert!(!set.remove(&3));
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&2));
+    /// assert_eq!(set.first(), &2);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q> + Ord,
+        Q: ?Sized + Ord,
+    {
+        self.try_remove(value).unwrap_or(false)
+    }
+
+    /// Removes a value from the set, returning whether it was in the set.
+    /// Returns [`EmptyError`] if the value is the only value in the set.
+    pub fn try_remove<Q>(&mut self, value: &Q) -> Result<bool, EmptyError>
+    where
+        T: Borrow<Q> + Ord,
+        Q: ?Sized + Ord,
+    {
+        // See `NonEmptyBTreeMap::try_remove`.
+        if self.0.len() > 1 {
+            Ok(self.0.remove(value))
+        } else if self.0.contains(value) {
+            Err(EmptyError)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Removes and returns the smallest element of the set.
+    /// Returns `None` if there is only one element in the set.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_first()
+        }
+    }
+
+    /// Removes and returns the largest element of the set.
+    /// Returns `None` if there is only one element in the set.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<T>
+    where
+        T: Ord,
+    {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_last()
+        }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the elements of the set, in sorted order.
+    #[inline]
+    pub fn ne_iter(&self) -> Iter<'_, T> {
+        Iter { set: self }
+    }
+
+    /// Creates a [non-empty iterator](NonEmptyIterator) that moves each element out of the set.
+    #[inline]
+    pub fn into_ne_iter(self) -> IntoIter<T> {
+        IntoIter { set: self }
+    }
+}
+
+impl<T: Ord> From<NonEmpty<T>> for NonEmptyBTreeSet<T> {
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        Self::from_ne_iter(v.into_ne_iter())
+    }
+}
+
+impl<T> From<NonEmptyBTreeSet<T>> for BTreeSet<T> {
+    #[inline]
+    fn from(set: NonEmptyBTreeSet<T>) -> Self {
+        set.0
+    }
+}
+
+impl<T> TryFrom<BTreeSet<T>> for NonEmptyBTreeSet<T> {
+    type Error = EmptyContainerError<BTreeSet<T>>;
+    fn try_from(set: BTreeSet<T>) -> Result<Self, Self::Error> {
+        if set.is_empty() {
+            Err(EmptyContainerError(set))
+        } else {
+            Ok(Self(set))
+        }
+    }
+}
+
+impl<T: Ord> FromNonEmptyIterator<T> for NonEmptyBTreeSet<T> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let set = BTreeSet::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(set) }
+    }
+}
+
+impl<T> ops::Deref for NonEmptyBTreeSet<T> {
+    type Target = BTreeSet<T>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<BTreeSet<T>> for NonEmptyBTreeSet<T> {
+    #[inline]
+    fn as_ref(&self) -> &BTreeSet<T> {
+        &self.0
+    }
+}
+
+impl<T: Ord> Extend<T> for NonEmptyBTreeSet<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyBTreeSet<T> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a NonEmptyBTreeSet<T> {
+    type Item = &'a T;
+    type IntoIter = btree_set::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for NonEmptyBTreeSet<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de> + Ord> Deserialize<'de> for NonEmptyBTreeSet<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<BTreeSet<T>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty set"))
+    }
+}
+
+/// Non-empty iterator over the elements of a [`NonEmptyBTreeSet`].
+///
+/// This is created by [`NonEmptyBTreeSet::ne_iter`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, T> {
+    set: &'a NonEmptyBTreeSet<T>,
+}
+impl<'a, T> Clone for Iter<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { set: self.set }
+    }
+}
+impl<'a, T> IntoIterator for Iter<'a, T> {
+    type Item = &'a T;
+    type IntoIter = btree_set::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.0.iter()
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for Iter<'a, T> {}
+
+/// Non-empty iterator that moves the elements out of a [`NonEmptyBTreeSet`].
+///
+/// This is created by [`NonEmptyBTreeSet::into_ne_iter`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<T> {
+    set: NonEmptyBTreeSet<T>,
+}
+impl<T> IntoIterator for IntoIter<T> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.0.into_iter()
+    }
+}
+unsafe impl<T> NonEmptyIterator for IntoIter<T> {}
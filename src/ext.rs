@@ -0,0 +1,274 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::btree_map::{self, BTreeMap};
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeSet, BinaryHeap, VecDeque};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::hash_map::{self, HashMap, RandomState};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    EmptyContainerError, NonEmpty, NonEmptyBTreeMap, NonEmptyBTreeSet, NonEmptyBinaryHeap,
+    NonEmptyString, NonEmptyVecDeque,
+};
+#[cfg(feature = "std")]
+use crate::{NonEmptyHashMap, NonEmptyHashSet};
+use crate::{NonEmptySlice, NonEmptyStr};
+
+/// Borrows a slice-like type as its non-empty counterpart.
+/// # Example
+/// ```
+/// # use non_empty_vec::AsNonEmpty;
+/// let v = vec![1, 2, 3];
+/// assert_eq!(v.as_non_empty().map(|s| s.first()), Some(&1));
+/// assert!(v[..0].as_non_empty().is_none());
+/// assert_eq!("abc".as_non_empty().map(|s| s.last_char()), Some('c'));
+/// ```
+pub trait AsNonEmpty {
+    /// The non-empty counterpart of `Self`.
+    type Target: ?Sized;
+
+    /// Returns `None` if `self` is empty.
+    fn as_non_empty(&self) -> Option<&Self::Target>;
+    /// Returns `None` if `self` is empty.
+    fn as_non_empty_mut(&mut self) -> Option<&mut Self::Target>;
+}
+
+impl<T> AsNonEmpty for [T] {
+    type Target = NonEmptySlice<T>;
+    #[inline]
+    fn as_non_empty(&self) -> Option<&NonEmptySlice<T>> {
+        NonEmptySlice::from_slice(self)
+    }
+    #[inline]
+    fn as_non_empty_mut(&mut self) -> Option<&mut NonEmptySlice<T>> {
+        NonEmptySlice::from_mut_slice(self)
+    }
+}
+
+impl AsNonEmpty for str {
+    type Target = NonEmptyStr;
+    #[inline]
+    fn as_non_empty(&self) -> Option<&NonEmptyStr> {
+        NonEmptyStr::new(self)
+    }
+    #[inline]
+    fn as_non_empty_mut(&mut self) -> Option<&mut NonEmptyStr> {
+        NonEmptyStr::new_mut(self)
+    }
+}
+
+/// Converts a collection into its non-empty counterpart, giving it back if it is empty.
+///
+/// This is a shorthand for the `TryFrom` conversions of each collection.
+/// # Example
+/// ```
+/// # use non_empty_vec::{ne_vec, IntoNonEmpty};
+/// assert_eq!(vec![1, 2].into_non_empty(), Ok(ne_vec![1, 2]));
+///
+/// let err = Vec::<i32>::with_capacity(8).into_non_empty().unwrap_err();
+/// assert!(err.into_inner().capacity() >= 8);
+///
+/// let boxed: Box<[i32]> = Box::new([1]);
+/// assert_eq!(boxed.into_non_empty().unwrap().first(), &1);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait IntoNonEmpty: Sized {
+    /// The non-empty counterpart of `Self`.
+    type Target;
+
+    /// Returns the input in an [`EmptyContainerError`] if it is empty.
+    fn into_non_empty(self) -> Result<Self::Target, EmptyContainerError<Self>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoNonEmpty for Vec<T> {
+    type Target = NonEmpty<T>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmpty<T>, EmptyContainerError<Self>> {
+        NonEmpty::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoNonEmpty for Box<[T]> {
+    type Target = Box<NonEmptySlice<T>>;
+    #[inline]
+    fn into_non_empty(self) -> Result<Box<NonEmptySlice<T>>, EmptyContainerError<Self>> {
+        <Box<NonEmptySlice<T>>>::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IntoNonEmpty for String {
+    type Target = NonEmptyString;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyString, EmptyContainerError<Self>> {
+        NonEmptyString::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl IntoNonEmpty for Box<str> {
+    type Target = Box<NonEmptyStr>;
+    #[inline]
+    fn into_non_empty(self) -> Result<Box<NonEmptyStr>, EmptyContainerError<Self>> {
+        <Box<NonEmptyStr>>::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoNonEmpty for VecDeque<T> {
+    type Target = NonEmptyVecDeque<T>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyVecDeque<T>, EmptyContainerError<Self>> {
+        NonEmptyVecDeque::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoNonEmpty for BinaryHeap<T> {
+    type Target = NonEmptyBinaryHeap<T>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyBinaryHeap<T>, EmptyContainerError<Self>> {
+        NonEmptyBinaryHeap::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, V> IntoNonEmpty for BTreeMap<K, V> {
+    type Target = NonEmptyBTreeMap<K, V>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyBTreeMap<K, V>, EmptyContainerError<Self>> {
+        NonEmptyBTreeMap::try_from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoNonEmpty for BTreeSet<T> {
+    type Target = NonEmptyBTreeSet<T>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyBTreeSet<T>, EmptyContainerError<Self>> {
+        NonEmptyBTreeSet::try_from(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, S> IntoNonEmpty for HashMap<K, V, S> {
+    type Target = NonEmptyHashMap<K, V, S>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyHashMap<K, V, S>, EmptyContainerError<Self>> {
+        NonEmptyHashMap::try_from(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, S> IntoNonEmpty for HashSet<T, S> {
+    type Target = NonEmptyHashSet<T, S>;
+    #[inline]
+    fn into_non_empty(self) -> Result<NonEmptyHashSet<T, S>, EmptyContainerError<Self>> {
+        NonEmptyHashSet::try_from(self)
+    }
+}
+
+/// Extension methods for iterators and other [`IntoIterator`] types, producing non-empty collections.
+#[cfg(feature = "alloc")]
+pub trait IteratorExt: IntoIterator + Sized {
+    /// Collects the items into a [`NonEmpty`] vector, or returns `None` if there are no items.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, IteratorExt};
+    /// assert_eq!((1..4).collect_non_empty(), Some(ne_vec![1, 2, 3]));
+    /// assert_eq!((1..1).collect_non_empty(), None);
+    /// ```
+    #[inline]
+    fn collect_non_empty(self) -> Option<NonEmpty<Self::Item>> {
+        NonEmpty::try_from(self.into_iter().collect::<Vec<_>>()).ok()
+    }
+
+    /// Groups the values of key-value pairs into a [`HashMap`] of non-empty buckets,
+    /// keeping the values in their original order.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, IteratorExt};
+    /// let map = vec![("a", 1), ("b", 2), ("a", 3)].into_group_map();
+    /// assert_eq!(map["a"], ne_vec![1, 3]);
+    /// assert_eq!(map["b"].first(), &2);
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    fn into_group_map<K, V>(self) -> HashMap<K, NonEmpty<V>>
+    where
+        Self: IntoIterator<Item = (K, V)>,
+        K: Eq + Hash,
+    {
+        self.into_group_map_with_hasher(RandomState::new())
+    }
+
+    /// Like [`into_group_map`](#method.into_group_map), but the map uses the given hash builder to hash keys.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, IteratorExt};
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::BuildHasherDefault;
+    ///
+    /// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+    /// let map = (1..=5).map(|i| (i % 2, i)).into_group_map_with_hasher(hasher);
+    /// assert_eq!(map[&1], ne_vec![1, 3, 5]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_group_map_with_hasher<K, V, S>(self, hash_builder: S) -> HashMap<K, NonEmpty<V>, S>
+    where
+        Self: IntoIterator<Item = (K, V)>,
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut map = HashMap::<K, NonEmpty<V>, S>::with_hasher(hash_builder);
+        for (k, v) in self {
+            match map.entry(k) {
+                hash_map::Entry::Occupied(mut e) => e.get_mut().push(v),
+                hash_map::Entry::Vacant(e) => {
+                    e.insert(NonEmpty::new(v));
+                }
+            }
+        }
+        map
+    }
+
+    /// Groups the values of key-value pairs into a [`BTreeMap`] of non-empty buckets,
+    /// keeping the values in their original order.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, IteratorExt};
+    /// let words = ["apple", "bean", "avocado"];
+    /// let map = words.iter().map(|w| (w.len(), *w)).into_group_btree_map();
+    /// assert_eq!(map.keys().copied().collect::<Vec<_>>(), [4, 5, 7]);
+    /// assert_eq!(map[&5], ne_vec!["apple"]);
+    /// ```
+    fn into_group_btree_map<K, V>(self) -> BTreeMap<K, NonEmpty<V>>
+    where
+        Self: IntoIterator<Item = (K, V)>,
+        K: Ord,
+    {
+        let mut map = BTreeMap::<K, NonEmpty<V>>::new();
+        for (k, v) in self {
+            match map.entry(k) {
+                btree_map::Entry::Occupied(mut e) => e.get_mut().push(v),
+                btree_map::Entry::Vacant(e) => {
+                    e.insert(NonEmpty::new(v));
+                }
+            }
+        }
+        map
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: IntoIterator> IteratorExt for I {}
@@ -0,0 +1,442 @@
+//! A [`HashMap`] that is guaranteed to contain at least one entry.
+
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+use std::collections::hash_map::{self, HashMap, RandomState};
+
+use crate::{EmptyContainerError, EmptyError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty hash map, ensure non empty by construction.
+/// Inherits `HashMap`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `remove` returns `None` if there is only one entry in it.
+/// * `keys`, `values` and `values_mut` return [non-empty iterators](NonEmptyIterator).
+#[derive(Clone)]
+pub struct NonEmptyHashMap<K, V, S = RandomState>(HashMap<K, V, S>);
+
+impl<K: Eq + Hash, V> NonEmptyHashMap<K, V> {
+    /// Creates a map containing a single entry.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyHashMap;
+    /// let map = NonEmptyHashMap::new("a", 1);
+    /// assert_eq!(map.any_entry(), (&"a", &1));
+    /// ```
+    #[inline]
+    pub fn new(key: K, value: V) -> Self {
+        Self::with_hasher(key, value, RandomState::new())
+    }
+}
+
+impl<K, V, S> NonEmptyHashMap<K, V, S> {
+    /// Creates a map containing a single entry, which will use the given hash builder to hash keys.
+    #[inline]
+    pub fn with_hasher(key: K, value: V, hash_builder: S) -> Self
+    where
+        K: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut map = HashMap::with_hasher(hash_builder);
+        map.insert(key, value);
+        Self(map)
+    }
+
+    /// Constructs a non-empty map without checking its size.
+    ///
+    /// # Safety
+    /// `map` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(map: HashMap<K, V, S>) -> Self {
+        debug_assert!(!map.is_empty());
+        Self(map)
+    }
+
+    #[inline]
+    pub fn as_hash_map(&self) -> &HashMap<K, V, S> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns an arbitrary entry of the map.
+    ///
+    /// This is the same entry that iteration would yield first.
+    #[inline]
+    pub fn any_entry(&self) -> (&K, &V) {
+        match self.0.iter().next() {
+            Some(entry) => entry,
+            // SAFETY: This map is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, K, V> {
+        self.0.iter_mut()
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the entries of the map, in arbitrary order.
+    #[inline]
+    pub fn ne_iter(&self) -> Iter<'_, K, V, S> {
+        Iter { map: self }
+    }
+
+    /// Creates a [non-empty iterator](NonEmptyIterator) that moves each entry out of the map.
+    #[inline]
+    pub fn into_ne_iter(self) -> IntoIter<K, V, S> {
+        IntoIter { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the keys of the map, in arbitrary order.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V, S> {
+        Keys { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the values of the map, in arbitrary order.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V, S> {
+        Values { map: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over mutable references to the values of the map,
+    /// in arbitrary order.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V, S> {
+        ValuesMut { map: self }
+    }
+}
+
+impl<K, V, S> NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.0.get_mut(key)
+    }
+
+    /// Inserts a key-value pair into the map, returning the old value if the key was already present.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes a key from the map, returning its value.
+    /// Returns `None` if the key is not in the map, or if it is the only key in the map.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyHashMap};
+    /// let mut map: NonEmptyHashMap<_, _> = NonEmptyHashMap::from(ne_vec![(1, 'a'), (2, 'b')]);
+    /// assert_eq!(map.remove(&3), None);
+    /// assert_eq!(map.remove(&1), Some('a'));
+    /// assert_eq!(map.remove(&2), None);
+    /// assert_eq!(map.any_entry(), (&2, &'b'));
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.try_remove(key).ok().flatten()
+    }
+
+    /// Removes a key from the map, returning its value if it was in the map.
+    /// Returns [`EmptyError`] if the key is the only key in the map.
+    pub fn try_remove<Q>(&mut self, key: &Q) -> Result<Option<V>, EmptyError>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        // The inner map is never asked to remove the only entry, so that an inconsistent
+        // `Hash` or `Eq` can't make the lookup and the removal disagree and leave the map empty.
+        if self.0.len() > 1 {
+            Ok(self.0.remove(key))
+        } else if self.0.contains_key(key) {
+            Err(EmptyError)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Collects the pairs into a map. If a key occurs more than once, the last value is kept.
+impl<K, V, S> From<NonEmpty<(K, V)>> for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from(v: NonEmpty<(K, V)>) -> Self {
+        Self::from_ne_iter(v.into_ne_iter())
+    }
+}
+
+impl<K, V, S> From<NonEmptyHashMap<K, V, S>> for HashMap<K, V, S> {
+    #[inline]
+    fn from(map: NonEmptyHashMap<K, V, S>) -> Self {
+        map.0
+    }
+}
+
+impl<K, V, S> TryFrom<HashMap<K, V, S>> for NonEmptyHashMap<K, V, S> {
+    type Error = EmptyContainerError<HashMap<K, V, S>>;
+    fn try_from(map: HashMap<K, V, S>) -> Result<Self, Self::Error> {
+        if map.is_empty() {
+            Err(EmptyContainerError(map))
+        } else {
+            Ok(Self(map))
+        }
+    }
+}
+
+impl<K, V, S> FromNonEmptyIterator<(K, V)> for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = (K, V)>,
+    {
+        let map = HashMap::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(map) }
+    }
+}
+
+impl<K, V, S> ops::Deref for NonEmptyHashMap<K, V, S> {
+    type Target = HashMap<K, V, S>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<K, V, S> AsRef<HashMap<K, V, S>> for NonEmptyHashMap<K, V, S> {
+    #[inline]
+    fn as_ref(&self) -> &HashMap<K, V, S> {
+        &self.0
+    }
+}
+
+impl<K, Q, V, S> ops::Index<&Q> for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: ?Sized + Eq + Hash,
+    S: BuildHasher,
+{
+    type Output = V;
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        &self.0[key]
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for NonEmptyHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NonEmptyHashMap").field(&self.0).finish()
+    }
+}
+
+impl<K, V, S> PartialEq for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<K, V, S> Eq for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Eq,
+    S: BuildHasher,
+{
+}
+
+impl<K, V, S> Extend<(K, V)> for NonEmptyHashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<K, V, S> IntoIterator for NonEmptyHashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, K, V, S> IntoIterator for &'a NonEmptyHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, K, V, S> IntoIterator for &'a mut NonEmptyHashMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = hash_map::IterMut<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K: Serialize, V: Serialize, S> Serialize for NonEmptyHashMap<K, V, S> {
+    fn serialize<S2: Serializer>(&self, serializer: S2) -> Result<S2::Ok, S2::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V, S> Deserialize<'de> for NonEmptyHashMap<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<HashMap<K, V, S>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty map"))
+    }
+}
+
+/// Non-empty iterator over the entries of a [`NonEmptyHashMap`].
+///
+/// This is created by [`NonEmptyHashMap::ne_iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, K, V, S> {
+    map: &'a NonEmptyHashMap<K, V, S>,
+}
+impl<'a, K, V, S> Clone for Iter<'a, K, V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V, S> IntoIterator for Iter<'a, K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = hash_map::Iter<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.iter()
+    }
+}
+unsafe impl<'a, K, V, S> NonEmptyIterator for Iter<'a, K, V, S> {}
+
+/// Non-empty iterator that moves the entries out of a [`NonEmptyHashMap`].
+///
+/// This is created by [`NonEmptyHashMap::into_ne_iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<K, V, S> {
+    map: NonEmptyHashMap<K, V, S>,
+}
+impl<K, V, S> IntoIterator for IntoIter<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = hash_map::IntoIter<K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.into_iter()
+    }
+}
+unsafe impl<K, V, S> NonEmptyIterator for IntoIter<K, V, S> {}
+
+/// Non-empty iterator over the keys of a [`NonEmptyHashMap`].
+///
+/// This is created by [`NonEmptyHashMap::keys`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Keys<'a, K, V, S> {
+    map: &'a NonEmptyHashMap<K, V, S>,
+}
+impl<'a, K, V, S> Clone for Keys<'a, K, V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V, S> IntoIterator for Keys<'a, K, V, S> {
+    type Item = &'a K;
+    type IntoIter = hash_map::Keys<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.keys()
+    }
+}
+unsafe impl<'a, K, V, S> NonEmptyIterator for Keys<'a, K, V, S> {}
+
+/// Non-empty iterator over the values of a [`NonEmptyHashMap`].
+///
+/// This is created by [`NonEmptyHashMap::values`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Values<'a, K, V, S> {
+    map: &'a NonEmptyHashMap<K, V, S>,
+}
+impl<'a, K, V, S> Clone for Values<'a, K, V, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { map: self.map }
+    }
+}
+impl<'a, K, V, S> IntoIterator for Values<'a, K, V, S> {
+    type Item = &'a V;
+    type IntoIter = hash_map::Values<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.values()
+    }
+}
+unsafe impl<'a, K, V, S> NonEmptyIterator for Values<'a, K, V, S> {}
+
+/// Non-empty iterator over mutable references to the values of a [`NonEmptyHashMap`].
+///
+/// This is created by [`NonEmptyHashMap::values_mut`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ValuesMut<'a, K, V, S> {
+    map: &'a mut NonEmptyHashMap<K, V, S>,
+}
+impl<'a, K, V, S> IntoIterator for ValuesMut<'a, K, V, S> {
+    type Item = &'a mut V;
+    type IntoIter = hash_map::ValuesMut<'a, K, V>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.0.values_mut()
+    }
+}
+unsafe impl<'a, K, V, S> NonEmptyIterator for ValuesMut<'a, K, V, S> {}
@@ -0,0 +1,320 @@
+//! A [`HashSet`] that is guaranteed to contain at least one element.
+
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+use std::collections::hash_map::RandomState;
+use std::collections::hash_set::{self, HashSet};
+
+use crate::{EmptyContainerError, EmptyError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty hash set, ensure non empty by construction.
+/// Inherits `HashSet`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `remove` won't remove the only element in it.
+#[derive(Clone)]
+pub struct NonEmptyHashSet<T, S = RandomState>(HashSet<T, S>);
+
+impl<T: Eq + Hash> NonEmptyHashSet<T> {
+    /// Creates a set containing a single element.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyHashSet;
+    /// let set = NonEmptyHashSet::new("a");
+    /// assert_eq!(set.any_element(), &"a");
+    /// ```
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self::with_hasher(value, RandomState::new())
+    }
+}
+
+impl<T, S> NonEmptyHashSet<T, S> {
+    /// Creates a set containing a single element, which will use the given hash builder to hash values.
+    #[inline]
+    pub fn with_hasher(value: T, hash_builder: S) -> Self
+    where
+        T: Eq + Hash,
+        S: BuildHasher,
+    {
+        let mut set = HashSet::with_hasher(hash_builder);
+        set.insert(value);
+        Self(set)
+    }
+
+    /// Constructs a non-empty set without checking its size.
+    ///
+    /// # Safety
+    /// `set` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(set: HashSet<T, S>) -> Self {
+        debug_assert!(!set.is_empty());
+        Self(set)
+    }
+
+    #[inline]
+    pub fn as_hash_set(&self) -> &HashSet<T, S> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns an arbitrary element of the set.
+    ///
+    /// This is the same element that iteration would yield first.
+    #[inline]
+    pub fn any_element(&self) -> &T {
+        match self.0.iter().next() {
+            Some(value) => value,
+            // SAFETY: This set is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the elements of the set, in arbitrary order.
+    #[inline]
+    pub fn ne_iter(&self) -> Iter<'_, T, S> {
+        Iter { set: self }
+    }
+
+    /// Creates a [non-empty iterator](NonEmptyIterator) that moves each element out of the set.
+    #[inline]
+    pub fn into_ne_iter(self) -> IntoIter<T, S> {
+        IntoIter { set: self }
+    }
+}
+
+impl<T, S> NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    /// Adds a value to the set, returning whether it was newly inserted.
+    #[inline]
+    pub fn insert(&mut self, value: T) -> bool {
+        self.0.insert(value)
+    }
+
+    /// Removes a value from the set, returning whether it was removed.
+    /// Returns `false` if the value is not in the set, or if it is the only value in the set.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyHashSet};
+    /// let mut set: NonEmptyHashSet<_> = NonEmptyHashSet::from(ne_vec![1, 2]);
+    /// assert!(!set.remove(&3));
+    /// assert!(set.remove(&1));
+    /// assert!(!set.remove(&2));
+    /// assert_eq!(set.any_element(), &2);
+    /// ```
+    #[inline]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.try_remove(value).unwrap_or(false)
+    }
+
+    /// Removes a value from the set, returning whether it was in the set.
+    /// Returns [`EmptyError`] if the value is the only value in the set.
+    pub fn try_remove<Q>(&mut self, value: &Q) -> Result<bool, EmptyError>
+    where
+        T: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        // See `NonEmptyHashMap::try_remove`.
+        if self.0.len() > 1 {
+            Ok(self.0.remove(value))
+        } else if self.0.contains(value) {
+            Err(EmptyError)
+        } else {
+            Ok(false)
+        }
+    }
+}
+
+impl<T, S> From<NonEmpty<T>> for NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        Self::from_ne_iter(v.into_ne_iter())
+    }
+}
+
+impl<T, S> From<NonEmptyHashSet<T, S>> for HashSet<T, S> {
+    #[inline]
+    fn from(set: NonEmptyHashSet<T, S>) -> Self {
+        set.0
+    }
+}
+
+impl<T, S> TryFrom<HashSet<T, S>> for NonEmptyHashSet<T, S> {
+    type Error = EmptyContainerError<HashSet<T, S>>;
+    fn try_from(set: HashSet<T, S>) -> Result<Self, Self::Error> {
+        if set.is_empty() {
+            Err(EmptyContainerError(set))
+        } else {
+            Ok(Self(set))
+        }
+    }
+}
+
+impl<T, S> FromNonEmptyIterator<T> for NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let set = HashSet::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(set) }
+    }
+}
+
+impl<T, S> ops::Deref for NonEmptyHashSet<T, S> {
+    type Target = HashSet<T, S>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, S> AsRef<HashSet<T, S>> for NonEmptyHashSet<T, S> {
+    #[inline]
+    fn as_ref(&self) -> &HashSet<T, S> {
+        &self.0
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for NonEmptyHashSet<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("NonEmptyHashSet").field(&self.0).finish()
+    }
+}
+
+impl<T, S> PartialEq for NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+impl<T, S> Eq for NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+}
+
+impl<T, S> Extend<T> for NonEmptyHashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T, S> IntoIterator for NonEmptyHashSet<T, S> {
+    type Item = T;
+    type IntoIter = hash_set::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, T, S> IntoIterator for &'a NonEmptyHashSet<T, S> {
+    type Item = &'a T;
+    type IntoIter = hash_set::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize, S> Serialize for NonEmptyHashSet<T, S> {
+    fn serialize<S2: Serializer>(&self, serializer: S2) -> Result<S2::Ok, S2::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, S> Deserialize<'de> for NonEmptyHashSet<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<HashSet<T, S>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty set"))
+    }
+}
+
+/// Non-empty iterator over the elements of a [`NonEmptyHashSet`].
+///
+/// This is created by [`NonEmptyHashSet::ne_iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, T, S> {
+    set: &'a NonEmptyHashSet<T, S>,
+}
+impl<'a, T, S> Clone for Iter<'a, T, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { set: self.set }
+    }
+}
+impl<'a, T, S> IntoIterator for Iter<'a, T, S> {
+    type Item = &'a T;
+    type IntoIter = hash_set::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.0.iter()
+    }
+}
+unsafe impl<'a, T, S> NonEmptyIterator for Iter<'a, T, S> {}
+
+/// Non-empty iterator that moves the elements out of a [`NonEmptyHashSet`].
+///
+/// This is created by [`NonEmptyHashSet::into_ne_iter`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<T, S> {
+    set: NonEmptyHashSet<T, S>,
+}
+impl<T, S> IntoIterator for IntoIter<T, S> {
+    type Item = T;
+    type IntoIter = hash_set::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.0.into_iter()
+    }
+}
+unsafe impl<T, S> NonEmptyIterator for IntoIter<T, S> {}
@@ -0,0 +1,671 @@
+//! Iterators that are guaranteed to yield at least one item.
+//!
+//! The [`NonEmptyIterator`] trait mirrors a subset of [`Iterator`], but its adapters
+//! preserve the guarantee, so the items can be [collected](NonEmptyIterator::collect)
+//! into a [`NonEmpty`] vector without checking the length at runtime.
+//!
+//! Non-empty iterators are not [`Iterator`]s themselves (otherwise the methods of both traits would
+//! clash), but they all implement [`IntoIterator`], so they can still be used in `for` loops
+//! or turned into a normal iterator when the guarantee is no longer needed.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::iter;
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use crate::NonEmpty;
+use crate::NonEmptySlice;
+
+/// An [`IntoIterator`] that is guaranteed to yield at least one item.
+///
+/// # Safety
+/// Implementors must ensure that the iterator returned by [`into_iter`](IntoIterator::into_iter)
+/// always yields at least one item. Unsafe code, such as [`collect`](#method.collect),
+/// relies on this.
+pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
+    /// Splits the iterator into its first item, and an iterator over the rest of the items.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// let (first, rest) = v.ne_iter().next();
+    /// assert_eq!(first, &1);
+    /// assert!(rest.eq(&[2, 3]));
+    /// ```
+    #[inline]
+    fn next(self) -> (Self::Item, Self::IntoIter) {
+        let mut iter = self.into_iter();
+        match iter.next() {
+            Some(first) => (first, iter),
+            // SAFETY: Implementors guarantee that there is at least one item.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns the first item of the iterator.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.ne_iter().map(|i| i * 10).first(), 10);
+    /// ```
+    #[inline]
+    fn first(self) -> Self::Item {
+        self.next().0
+    }
+
+    /// Takes a closure and creates an iterator which calls that closure on each item.
+    /// See [`Iterator::map`].
+    #[inline]
+    fn map<U, F>(self, f: F) -> Map<Self, F>
+    where
+        F: FnMut(Self::Item) -> U,
+    {
+        Map { iter: self, f }
+    }
+
+    /// Creates an iterator which gives the current iteration count as well as the next item.
+    /// See [`Iterator::enumerate`].
+    #[inline]
+    fn enumerate(self) -> Enumerate<Self> {
+        Enumerate { iter: self }
+    }
+
+    /// Zips up two non-empty iterators into a single non-empty iterator of pairs.
+    /// See [`Iterator::zip`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let a = ne_vec![1, 2, 3];
+    /// let b = ne_vec!['a', 'b'];
+    /// let zipped: NonEmpty<_> = a.ne_iter().copied().zip(b.ne_iter().copied()).collect();
+    /// assert_eq!(zipped, ne_vec![(1, 'a'), (2, 'b')]);
+    /// ```
+    #[inline]
+    fn zip<U>(self, other: U) -> Zip<Self, U>
+    where
+        U: NonEmptyIterator,
+    {
+        Zip { a: self, b: other }
+    }
+
+    /// Takes two iterators and creates a new iterator over both in sequence.
+    /// Only the first iterator needs to be non-empty.
+    /// See [`Iterator::chain`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 2];
+    /// let chained: NonEmpty<_> = v.into_ne_iter().chain(vec![]).collect();
+    /// assert_eq!(chained, ne_vec![1, 2]);
+    /// ```
+    #[inline]
+    fn chain<U>(self, other: U) -> Chain<Self, U>
+    where
+        U: IntoIterator<Item = Self::Item>,
+    {
+        Chain { a: self, b: other }
+    }
+
+    /// Creates an iterator which clones all of its items.
+    /// See [`Iterator::cloned`].
+    #[inline]
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        Self: NonEmptyIterator<Item = &'a T>,
+        T: 'a + Clone,
+    {
+        Cloned { iter: self }
+    }
+
+    /// Creates an iterator which copies all of its items.
+    /// See [`Iterator::copied`].
+    #[inline]
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        Self: NonEmptyIterator<Item = &'a T>,
+        T: 'a + Copy,
+    {
+        Copied { iter: self }
+    }
+
+    /// Reverses the direction of the iterator.
+    /// See [`Iterator::rev`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.ne_iter().rev().first(), &3);
+    /// ```
+    #[inline]
+    fn rev(self) -> Rev<Self>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        Rev { iter: self }
+    }
+
+    /// Does something with each item, before passing it on.
+    /// See [`Iterator::inspect`].
+    #[inline]
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        F: FnMut(&Self::Item),
+    {
+        Inspect { iter: self, f }
+    }
+
+    /// Maps each item to a non-empty iterator, and flattens the results.
+    /// See [`Iterator::flat_map`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 2];
+    /// let flat: NonEmpty<_> = v.into_ne_iter().flat_map(|i| ne_vec![i; i].into_ne_iter()).collect();
+    /// assert_eq!(flat, ne_vec![1, 2, 2]);
+    /// ```
+    #[inline]
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, F>
+    where
+        U: NonEmptyIterator,
+        F: FnMut(Self::Item) -> U,
+    {
+        FlatMap { iter: self, f }
+    }
+
+    /// Transforms the iterator into a collection, such as a [`NonEmpty`] vector.
+    /// Since there is always at least one item, no runtime check is needed.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// let doubled: NonEmpty<_> = v.ne_iter().map(|i| i * 2).collect();
+    /// assert_eq!(doubled, ne_vec![2, 4, 6]);
+    /// ```
+    #[inline]
+    fn collect<B>(self) -> B
+    where
+        B: FromNonEmptyIterator<Self::Item>,
+    {
+        B::from_ne_iter(self)
+    }
+}
+
+/// Conversion from a [`NonEmptyIterator`]. This is the non-empty counterpart to [`FromIterator`].
+pub trait FromNonEmptyIterator<T>: Sized {
+    /// Creates a value from a non-empty iterator.
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FromNonEmptyIterator<T> for NonEmpty<T> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let v = Vec::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { NonEmpty::new_unchecked(v) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> FromNonEmptyIterator<T> for Box<NonEmptySlice<T>> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        NonEmpty::from_ne_iter(iter).into_boxed_slice()
+    }
+}
+
+/// Non-empty iterator over the elements of a [`NonEmptySlice`].
+///
+/// This is created by [`NonEmptySlice::ne_iter`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Iter<'a, T> {
+    pub(crate) slice: &'a NonEmptySlice<T>,
+}
+impl<'a, T> Clone for Iter<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { slice: self.slice }
+    }
+}
+impl<'a, T> IntoIterator for Iter<'a, T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.as_slice().iter()
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for Iter<'a, T> {}
+
+/// Non-empty iterator over mutable references to the elements of a [`NonEmptySlice`].
+///
+/// This is created by [`NonEmptySlice::ne_iter_mut`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterMut<'a, T> {
+    pub(crate) slice: &'a mut NonEmptySlice<T>,
+}
+impl<'a, T> IntoIterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.as_mut_slice().iter_mut()
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for IterMut<'a, T> {}
+
+/// Wraps a chunk that is known to be non-empty.
+///
+/// This is only passed to iterators which never yield empty chunks.
+#[inline]
+pub(crate) fn ne_chunk<T>(chunk: &[T]) -> &NonEmptySlice<T> {
+    // SAFETY: The callers only yield non-empty chunks.
+    unsafe { NonEmptySlice::unchecked(chunk) }
+}
+/// Wraps a mutable chunk that is known to be non-empty.
+///
+/// This is only passed to iterators which never yield empty chunks.
+#[inline]
+pub(crate) fn ne_chunk_mut<T>(chunk: &mut [T]) -> &mut NonEmptySlice<T> {
+    // SAFETY: The callers only yield non-empty chunks.
+    unsafe { NonEmptySlice::unchecked_mut(chunk) }
+}
+
+/// Non-empty iterator over non-overlapping chunks of a [`NonEmptySlice`], starting at the beginning.
+///
+/// This is created by [`NonEmptySlice::chunks_nz`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<'a, T> {
+    pub(crate) slice: &'a NonEmptySlice<T>,
+    pub(crate) size: NonZeroUsize,
+}
+impl<'a, T> Clone for Chunks<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            size: self.size,
+        }
+    }
+}
+impl<'a, T> IntoIterator for Chunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::Chunks<'a, T>, fn(&'a [T]) -> &'a NonEmptySlice<T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.as_slice().chunks(self.size.get()).map(ne_chunk)
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for Chunks<'a, T> {}
+
+/// Non-empty iterator over mutable non-overlapping chunks of a [`NonEmptySlice`], starting at the beginning.
+///
+/// This is created by [`NonEmptySlice::chunks_mut_nz`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksMut<'a, T> {
+    pub(crate) slice: &'a mut NonEmptySlice<T>,
+    pub(crate) size: NonZeroUsize,
+}
+impl<'a, T> IntoIterator for ChunksMut<'a, T> {
+    type Item = &'a mut NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::ChunksMut<'a, T>, fn(&'a mut [T]) -> &'a mut NonEmptySlice<T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_mut_slice()
+            .chunks_mut(self.size.get())
+            .map(ne_chunk_mut)
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for ChunksMut<'a, T> {}
+
+/// Non-empty iterator over non-overlapping chunks of a [`NonEmptySlice`], starting at the end.
+///
+/// This is created by [`NonEmptySlice::rchunks_nz`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RChunks<'a, T> {
+    pub(crate) slice: &'a NonEmptySlice<T>,
+    pub(crate) size: NonZeroUsize,
+}
+impl<'a, T> Clone for RChunks<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            size: self.size,
+        }
+    }
+}
+impl<'a, T> IntoIterator for RChunks<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::RChunks<'a, T>, fn(&'a [T]) -> &'a NonEmptySlice<T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.as_slice().rchunks(self.size.get()).map(ne_chunk)
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for RChunks<'a, T> {}
+
+/// Non-empty iterator over mutable non-overlapping chunks of a [`NonEmptySlice`], starting at the end.
+///
+/// This is created by [`NonEmptySlice::rchunks_mut_nz`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RChunksMut<'a, T> {
+    pub(crate) slice: &'a mut NonEmptySlice<T>,
+    pub(crate) size: NonZeroUsize,
+}
+impl<'a, T> IntoIterator for RChunksMut<'a, T> {
+    type Item = &'a mut NonEmptySlice<T>;
+    type IntoIter =
+        iter::Map<slice::RChunksMut<'a, T>, fn(&'a mut [T]) -> &'a mut NonEmptySlice<T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_mut_slice()
+            .rchunks_mut(self.size.get())
+            .map(ne_chunk_mut)
+    }
+}
+unsafe impl<'a, T> NonEmptyIterator for RChunksMut<'a, T> {}
+
+/// Non-empty iterator over runs of a [`NonEmptySlice`], separated by a predicate.
+///
+/// This is created by [`NonEmptySlice::chunk_by`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkBy<'a, T, P> {
+    pub(crate) slice: &'a NonEmptySlice<T>,
+    pub(crate) pred: P,
+}
+impl<'a, T, P: Clone> Clone for ChunkBy<'a, T, P> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            pred: self.pred.clone(),
+        }
+    }
+}
+impl<'a, T, P> IntoIterator for ChunkBy<'a, T, P>
+where
+    P: FnMut(&T, &T) -> bool,
+{
+    type Item = &'a NonEmptySlice<T>;
+    type IntoIter = iter::Map<slice::ChunkBy<'a, T, P>, fn(&'a [T]) -> &'a NonEmptySlice<T>>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.as_slice().chunk_by(self.pred).map(ne_chunk)
+    }
+}
+unsafe impl<'a, T, P> NonEmptyIterator for ChunkBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
+/// Iterator over chunks of exactly the same length of a [`NonEmptySlice`].
+///
+/// This is created by [`NonEmptySlice::chunks_exact_nz`].
+pub type ChunksExact<'a, T> =
+    iter::Map<slice::ChunksExact<'a, T>, fn(&'a [T]) -> &'a NonEmptySlice<T>>;
+
+/// Iterator over mutable chunks of exactly the same length of a [`NonEmptySlice`].
+///
+/// This is created by [`NonEmptySlice::chunks_exact_mut_nz`].
+pub type ChunksExactMut<'a, T> =
+    iter::Map<slice::ChunksExactMut<'a, T>, fn(&'a mut [T]) -> &'a mut NonEmptySlice<T>>;
+
+/// Iterator over overlapping windows of a [`NonEmptySlice`].
+///
+/// This is created by [`NonEmptySlice::windows_nz`].
+pub type Windows<'a, T> = iter::Map<slice::Windows<'a, T>, fn(&'a [T]) -> &'a NonEmptySlice<T>>;
+
+/// Non-empty iterator that moves the elements out of a [`NonEmpty`] vector.
+///
+/// This is created by [`NonEmpty::into_ne_iter`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<T> {
+    pub(crate) vec: NonEmpty<T>,
+}
+#[cfg(feature = "alloc")]
+impl<T> IntoIterator for IntoIter<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+#[cfg(feature = "alloc")]
+unsafe impl<T> NonEmptyIterator for IntoIter<T> {}
+
+/// Non-empty iterator that maps the items of another. See [`NonEmptyIterator::map`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Map<I, F> {
+    iter: I,
+    f: F,
+}
+impl<I, U, F> IntoIterator for Map<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U;
+    type IntoIter = iter::Map<I::IntoIter, F>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().map(self.f)
+    }
+}
+unsafe impl<I, U, F> NonEmptyIterator for Map<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(I::Item) -> U,
+{
+}
+
+/// Non-empty iterator that yields the index of each item. See [`NonEmptyIterator::enumerate`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Enumerate<I> {
+    iter: I,
+}
+impl<I: NonEmptyIterator> IntoIterator for Enumerate<I> {
+    type Item = (usize, I::Item);
+    type IntoIter = iter::Enumerate<I::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().enumerate()
+    }
+}
+unsafe impl<I: NonEmptyIterator> NonEmptyIterator for Enumerate<I> {}
+
+/// Non-empty iterator over the items of two others, in pairs. See [`NonEmptyIterator::zip`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+impl<A: NonEmptyIterator, B: NonEmptyIterator> IntoIterator for Zip<A, B> {
+    type Item = (A::Item, B::Item);
+    type IntoIter = iter::Zip<A::IntoIter, B::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.a.into_iter().zip(self.b)
+    }
+}
+unsafe impl<A: NonEmptyIterator, B: NonEmptyIterator> NonEmptyIterator for Zip<A, B> {}
+
+/// Non-empty iterator over the items of two others, in sequence. See [`NonEmptyIterator::chain`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+}
+impl<A, B> IntoIterator for Chain<A, B>
+where
+    A: NonEmptyIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+    type IntoIter = iter::Chain<A::IntoIter, B::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.a.into_iter().chain(self.b)
+    }
+}
+unsafe impl<A, B> NonEmptyIterator for Chain<A, B>
+where
+    A: NonEmptyIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+}
+
+/// Non-empty iterator that clones the items of another. See [`NonEmptyIterator::cloned`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Cloned<I> {
+    iter: I,
+}
+impl<'a, I, T> IntoIterator for Cloned<I>
+where
+    I: NonEmptyIterator<Item = &'a T>,
+    T: 'a + Clone,
+{
+    type Item = T;
+    type IntoIter = iter::Cloned<I::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().cloned()
+    }
+}
+unsafe impl<'a, I, T> NonEmptyIterator for Cloned<I>
+where
+    I: NonEmptyIterator<Item = &'a T>,
+    T: 'a + Clone,
+{
+}
+
+/// Non-empty iterator that copies the items of another. See [`NonEmptyIterator::copied`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Copied<I> {
+    iter: I,
+}
+impl<'a, I, T> IntoIterator for Copied<I>
+where
+    I: NonEmptyIterator<Item = &'a T>,
+    T: 'a + Copy,
+{
+    type Item = T;
+    type IntoIter = iter::Copied<I::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().copied()
+    }
+}
+unsafe impl<'a, I, T> NonEmptyIterator for Copied<I>
+where
+    I: NonEmptyIterator<Item = &'a T>,
+    T: 'a + Copy,
+{
+}
+
+/// Non-empty iterator over the items of another, in reverse. See [`NonEmptyIterator::rev`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Rev<I> {
+    iter: I,
+}
+impl<I> IntoIterator for Rev<I>
+where
+    I: NonEmptyIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+    type Item = I::Item;
+    type IntoIter = iter::Rev<I::IntoIter>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().rev()
+    }
+}
+unsafe impl<I> NonEmptyIterator for Rev<I>
+where
+    I: NonEmptyIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+}
+
+/// Non-empty iterator that calls a closure with each item of another. See [`NonEmptyIterator::inspect`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Inspect<I, F> {
+    iter: I,
+    f: F,
+}
+impl<I, F> IntoIterator for Inspect<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(&I::Item),
+{
+    type Item = I::Item;
+    type IntoIter = iter::Inspect<I::IntoIter, F>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().inspect(self.f)
+    }
+}
+unsafe impl<I, F> NonEmptyIterator for Inspect<I, F>
+where
+    I: NonEmptyIterator,
+    F: FnMut(&I::Item),
+{
+}
+
+/// Non-empty iterator that flattens the non-empty iterators produced by a closure.
+/// See [`NonEmptyIterator::flat_map`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FlatMap<I, F> {
+    iter: I,
+    f: F,
+}
+impl<I, U, F> IntoIterator for FlatMap<I, F>
+where
+    I: NonEmptyIterator,
+    U: NonEmptyIterator,
+    F: FnMut(I::Item) -> U,
+{
+    type Item = U::Item;
+    type IntoIter = iter::FlatMap<I::IntoIter, U, F>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().flat_map(self.f)
+    }
+}
+unsafe impl<I, U, F> NonEmptyIterator for FlatMap<I, F>
+where
+    I: NonEmptyIterator,
+    U: NonEmptyIterator,
+    F: FnMut(I::Item) -> U,
+{
+}
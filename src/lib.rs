@@ -1,38 +1,150 @@
-use std::convert::TryFrom;
-use std::iter::FusedIterator;
-use std::num::NonZeroUsize;
-use std::ops::{self, RangeBounds};
-use std::slice::{Iter, IterMut, SliceIndex};
-use std::vec::IntoIter;
-
-#[cfg(feature = "serde")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec, vec::IntoIter, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+#[cfg(feature = "alloc")]
+use core::iter::FusedIterator;
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::mem::ManuallyDrop;
+use core::num::NonZeroUsize;
+use core::ops;
+#[cfg(feature = "alloc")]
+use core::ops::RangeBounds;
+#[cfg(feature = "alloc")]
+use core::slice::SliceIndex;
+use core::slice::{Iter, IterMut};
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
-/// Calls [`std::hint::unreachable_unchecked`] in release mode, and panics in debug mode.
+/// Calls [`core::hint::unreachable_unchecked`] in release mode, and panics in debug mode.
 macro_rules! unreachable_unchecked {
     () => {{
         #[cfg(debug_assertions)]
-        ::std::unreachable!();
+        ::core::unreachable!();
         #[allow(unreachable_code)]
-        ::std::hint::unreachable_unchecked()
+        ::core::hint::unreachable_unchecked()
     }};
 }
 
+#[cfg(feature = "alloc")]
+pub mod binary_heap;
+#[cfg(feature = "alloc")]
+pub mod btree_map;
+#[cfg(feature = "alloc")]
+pub mod btree_set;
+mod ext;
+#[cfg(feature = "std")]
+pub mod hash_map;
+#[cfg(feature = "std")]
+pub mod hash_set;
+pub mod iter;
+pub mod string;
+#[cfg(feature = "alloc")]
+pub mod validated;
+#[cfg(feature = "alloc")]
+pub mod vec_deque;
+
+#[doc(hidden)]
+pub mod __private {
+    use crate::{NonEmptySlice, NonEmptyStr};
+
+    #[cfg(feature = "alloc")]
+    pub use alloc::vec;
+
+    // These take `'static` references so that the macros can't be used with temporaries.
+    pub const fn ne_slice<T>(slice: &'static [T]) -> &'static NonEmptySlice<T> {
+        match NonEmptySlice::from_slice(slice) {
+            Some(slice) => slice,
+            None => ::core::panic!("`NonEmptySlice` must be non-empty"),
+        }
+    }
+
+    pub const fn ne_str(s: &'static str) -> &'static NonEmptyStr {
+        match NonEmptyStr::new(s) {
+            Some(s) => s,
+            None => ::core::panic!("`NonEmptyStr` must be non-empty"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use binary_heap::NonEmptyBinaryHeap;
+#[cfg(feature = "alloc")]
+pub use btree_map::NonEmptyBTreeMap;
+#[cfg(feature = "alloc")]
+pub use btree_set::NonEmptyBTreeSet;
+pub use ext::AsNonEmpty;
+#[cfg(feature = "alloc")]
+pub use ext::{IntoNonEmpty, IteratorExt};
+#[cfg(feature = "std")]
+pub use hash_map::NonEmptyHashMap;
+#[cfg(feature = "std")]
+pub use hash_set::NonEmptyHashSet;
+pub use iter::{FromNonEmptyIterator, NonEmptyIterator};
+pub use string::NonEmptyStr;
+#[cfg(feature = "alloc")]
+pub use string::NonEmptyString;
+#[cfg(feature = "alloc")]
+pub use validated::Validated;
+#[cfg(feature = "alloc")]
+pub use vec_deque::NonEmptyVecDeque;
+
 /// Non empty vector, ensure non empty by construction.
 /// Inherits `Vec`'s methods through `Deref` trait, not implement `DerefMut`.
 /// Overridden these methods:
 /// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
 /// * `first(_mut)`, `last(_mut)`, `split_first(_mut)`, `split_last(_mut)` don't return `Option`.
 /// * `pop` returns `None` if there is only one element in it.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct NonEmpty<T>(Vec<T>);
 
+#[cfg(feature = "alloc")]
 impl<T> NonEmpty<T> {
     #[inline]
     pub fn new(v: T) -> Self {
         Self(vec![v])
     }
 
+    /// Constructs a non-empty vector from its first element, followed by the elements of an iterator.
+    ///
+    /// The vector is allocated up front with room for the head and the lower bound
+    /// of the iterator's [`size_hint`](Iterator::size_hint), so exact-size iterators only allocate once.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty};
+    /// let v = NonEmpty::from_head_and_iter(1, 2..5);
+    /// assert_eq!(v, ne_vec![1, 2, 3, 4]);
+    /// assert!(Vec::from(v).capacity() >= 4);
+    /// ```
+    pub fn from_head_and_iter<I>(head: T, tail: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let tail = tail.into_iter();
+        let (lower, _) = tail.size_hint();
+        let mut v = Vec::with_capacity(lower.saturating_add(1));
+        v.push(head);
+        v.extend(tail);
+        Self(v)
+    }
+
     /// Constructs a non-empty vec without checking its size.
     ///
     /// # Safety
@@ -66,11 +178,328 @@ impl<T> NonEmpty<T> {
         self.0.push(v)
     }
 
+    /// Inserts an element at position `index`, shifting all elements after it to the right.
+    /// # Panics
+    /// If `index > len`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3];
+    /// v.insert(1, 4);
+    /// assert_eq!(v, ne_vec![1, 4, 2, 3]);
+    /// v.insert(4, 5);
+    /// assert_eq!(v, ne_vec![1, 4, 2, 3, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.0.insert(index, element)
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the left.
+    /// Returns `None` if there is only one element in the vector, like [`pop`](#method.pop).
+    /// # Panics
+    /// If `index` is out of bounds.
+    /// # Examples
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.remove(1), Some(2));
+    /// assert_eq!(v.remove(0), Some(1));
+    /// assert_eq!(v.remove(0), None);
+    /// assert_eq!(v, ne_vec![3]);
+    /// ```
+    /// ```should_panic
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3];
+    /// v.remove(3);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.try_remove(index).ok()
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to the left.
+    /// Returns [`EmptyError`] if there is only one element in the vector.
+    /// # Panics
+    /// If `index` is out of bounds.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, EmptyError};
+    /// let mut v = ne_vec![1, 2];
+    /// assert_eq!(v.try_remove(0), Ok(1));
+    /// assert_eq!(v.try_remove(0), Err(EmptyError));
+    /// ```
+    #[track_caller]
+    pub fn try_remove(&mut self, index: usize) -> Result<T, EmptyError> {
+        let len = self.0.len();
+        if index >= len {
+            panic!("removal index (is {}) should be < len (is {})", index, len);
+        }
+        if len == 1 {
+            Err(EmptyError)
+        } else {
+            Ok(self.0.remove(index))
+        }
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element of the vector.
+    /// This does not preserve ordering, but is *O*(1).
+    /// Returns `None` if there is only one element in the vector, like [`pop`](#method.pop).
+    /// # Panics
+    /// If `index` is out of bounds.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert_eq!(v.swap_remove(0), Some(1));
+    /// assert_eq!(v, ne_vec![4, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        self.try_swap_remove(index).ok()
+    }
+
+    /// Removes and returns the element at position `index`, replacing it with the last element of the vector.
+    /// Returns [`EmptyError`] if there is only one element in the vector.
+    /// # Panics
+    /// If `index` is out of bounds.
+    #[track_caller]
+    pub fn try_swap_remove(&mut self, index: usize) -> Result<T, EmptyError> {
+        let len = self.0.len();
+        if index >= len {
+            panic!(
+                "swap_remove index (is {}) should be < len (is {})",
+                index, len
+            );
+        }
+        if len == 1 {
+            Err(EmptyError)
+        } else {
+            Ok(self.0.swap_remove(index))
+        }
+    }
+
     #[inline]
     pub fn truncate(&mut self, len: NonZeroUsize) {
         self.0.truncate(len.get())
     }
 
+    /// Creates a [non-empty iterator](NonEmptyIterator) that moves each element out of the vector.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// let strings: NonEmpty<String> = v.into_ne_iter().map(|i| i.to_string()).collect();
+    /// assert_eq!(strings.as_slice(), ["1", "2", "3"]);
+    /// ```
+    #[inline]
+    pub fn into_ne_iter(self) -> iter::IntoIter<T> {
+        iter::IntoIter { vec: self }
+    }
+
+    /// Converts each element of the vector, producing a new non-empty vector.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.map(|i| i * 2), ne_vec![2, 4, 6]);
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> NonEmpty<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let v = self.0.into_iter().map(f).collect();
+        // SAFETY: Mapping preserves the length, so the vector is non-empty.
+        unsafe { NonEmpty::new_unchecked(v) }
+    }
+
+    /// Converts each element of the vector with a fallible function,
+    /// stopping at the first error.
+    ///
+    /// For functions returning an [`Option`], use [`try_map_opt`](#method.try_map_opt).
+    /// # Examples
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec!["1", "2", "3"];
+    /// assert_eq!(v.try_map(|s| s.parse::<i32>()), Ok(ne_vec![1, 2, 3]));
+    ///
+    /// let v = ne_vec!["1", "two", "3"];
+    /// assert!(v.try_map(|s| s.parse::<i32>()).is_err());
+    /// ```
+    #[inline]
+    pub fn try_map<U, E, F>(self, f: F) -> Result<NonEmpty<U>, E>
+    where
+        F: FnMut(T) -> Result<U, E>,
+    {
+        let v = self.0.into_iter().map(f).collect::<Result<_, _>>()?;
+        // SAFETY: Mapping preserves the length, so the vector is non-empty.
+        Ok(unsafe { NonEmpty::new_unchecked(v) })
+    }
+
+    /// Converts each element of the vector with a function returning an [`Option`],
+    /// stopping at the first `None`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec!['1', '2', '3'];
+    /// assert_eq!(v.try_map_opt(|c| c.to_digit(10)), Some(ne_vec![1, 2, 3]));
+    ///
+    /// let v = ne_vec!['1', 'x', '3'];
+    /// assert_eq!(v.try_map_opt(|c| c.to_digit(10)), None);
+    /// ```
+    #[inline]
+    pub fn try_map_opt<U, F>(self, f: F) -> Option<NonEmpty<U>>
+    where
+        F: FnMut(T) -> Option<U>,
+    {
+        let v = self.0.into_iter().map(f).collect::<Option<_>>()?;
+        // SAFETY: Mapping preserves the length, so the vector is non-empty.
+        Some(unsafe { NonEmpty::new_unchecked(v) })
+    }
+
+    /// Converts each element of the vector, reusing the allocation of this vector
+    /// if `T` and `U` have the same size and alignment.
+    ///
+    /// Otherwise, this is the same as [`map`](#method.map), collecting into a new allocation.
+    /// # Examples
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1_u32, 2, 3];
+    /// let ptr = v.as_ptr() as usize;
+    /// let v = v.map_in_place(|i| i as f32 / 2.0);
+    /// assert_eq!(v, ne_vec![0.5, 1.0, 1.5]);
+    /// assert_eq!(v.as_ptr() as usize, ptr);
+    /// ```
+    /// Mismatched layouts.
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1_u8, 2, 3].map_in_place(|i| i as u64 * 100);
+    /// assert_eq!(v, ne_vec![100, 200, 300]);
+    /// ```
+    pub fn map_in_place<U, F>(self, mut f: F) -> NonEmpty<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        if core::mem::size_of::<T>() != core::mem::size_of::<U>()
+            || core::mem::align_of::<T>() != core::mem::align_of::<U>()
+        {
+            return self.map(f);
+        }
+
+        /// Cleans up the buffer if `f` panics, when it holds some elements of each type.
+        struct Guard<T, U> {
+            ptr: *mut T,
+            len: usize,
+            cap: usize,
+            mapped: usize,
+            _marker: PhantomData<U>,
+        }
+        impl<T, U> Drop for Guard<T, U> {
+            fn drop(&mut self) {
+                unsafe {
+                    // SAFETY: `[0, mapped)` holds converted elements, the element at `mapped`
+                    // was moved into `f`, and the rest still hold the original elements.
+                    let mapped = core::slice::from_raw_parts_mut(self.ptr as *mut U, self.mapped);
+                    core::ptr::drop_in_place(mapped);
+                    let rest = core::slice::from_raw_parts_mut(
+                        self.ptr.add(self.mapped + 1),
+                        self.len - self.mapped - 1,
+                    );
+                    core::ptr::drop_in_place(rest);
+                    drop(Vec::from_raw_parts(self.ptr, 0, self.cap));
+                }
+            }
+        }
+
+        let mut v = ManuallyDrop::new(self.0);
+        let mut guard = Guard::<T, U> {
+            ptr: v.as_mut_ptr(),
+            len: v.len(),
+            cap: v.capacity(),
+            mapped: 0,
+            _marker: PhantomData,
+        };
+        while guard.mapped < guard.len {
+            unsafe {
+                // SAFETY: Each element is read exactly once, and its slot is then overwritten
+                // with the converted value, which has the same layout.
+                let slot = guard.ptr.add(guard.mapped);
+                let val = f(core::ptr::read(slot));
+                core::ptr::write(slot as *mut U, val);
+            }
+            guard.mapped += 1;
+        }
+        let guard = ManuallyDrop::new(guard);
+        // SAFETY: Every element has been converted, and `U` has the same layout as `T`,
+        // so the buffer can be reused. The length is unchanged, so it's still non-empty.
+        unsafe {
+            NonEmpty::new_unchecked(Vec::from_raw_parts(
+                guard.ptr as *mut U,
+                guard.len,
+                guard.cap,
+            ))
+        }
+    }
+
+    /// Splits the vector into its first element and a vector of the remaining elements.
+    /// This is the inverse of `From<(T, Vec<T>)>`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty};
+    /// let v = ne_vec![1, 2, 3];
+    /// let (first, rest) = v.into_first_and_rest();
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest, vec![2, 3]);
+    /// assert_eq!(NonEmpty::from((first, rest)), ne_vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_first_and_rest(self) -> (T, Vec<T>) {
+        let mut v = self.0;
+        let first = v.remove(0);
+        (first, v)
+    }
+
+    /// Splits the vector into a vector of all but the last element, and the last element.
+    /// This is the inverse of `From<(Vec<T>, T)>`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty};
+    /// let v = ne_vec![1, 2, 3];
+    /// let (init, last) = v.into_init_and_last();
+    /// assert_eq!(init, vec![1, 2]);
+    /// assert_eq!(last, 3);
+    /// assert_eq!(NonEmpty::from((init, last)), ne_vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_init_and_last(self) -> (Vec<T>, T) {
+        let mut v = self.0;
+        match v.pop() {
+            Some(last) => (v, last),
+            // SAFETY: This instance is non-empty, so there is always a last element.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns the only element of the vector, or gives the vector back if it has more than one element.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// assert_eq!(ne_vec![1].into_single(), Ok(1));
+    /// assert_eq!(ne_vec![1, 2].into_single(), Err(ne_vec![1, 2]));
+    /// ```
+    #[inline]
+    pub fn into_single(self) -> Result<T, Self> {
+        if self.0.len() == 1 {
+            Ok(self.into_init_and_last().1)
+        } else {
+            Err(self)
+        }
+    }
+
     /// Converts the vector into a boxed slice.
     ///
     /// Note that this will drop any excess capacity.
@@ -82,6 +511,7 @@ impl<T> NonEmpty<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<(Vec<T>, T)> for NonEmpty<T> {
     fn from((mut xs, x): (Vec<T>, T)) -> NonEmpty<T> {
         xs.push(x);
@@ -89,40 +519,139 @@ impl<T> From<(Vec<T>, T)> for NonEmpty<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<(T, Vec<T>)> for NonEmpty<T> {
-    fn from((x, mut xs): (T, Vec<T>)) -> NonEmpty<T> {
-        xs.insert(0, x);
-        NonEmpty(xs)
+    fn from((x, xs): (T, Vec<T>)) -> NonEmpty<T> {
+        NonEmpty::from_head_and_iter(x, xs)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<NonEmpty<T>> for Vec<T> {
     fn from(v: NonEmpty<T>) -> Self {
         v.0
     }
 }
 
+/// Fails to compile when evaluating `OK`, if `N` is zero.
+struct AssertNonZero<const N: usize>;
+impl<const N: usize> AssertNonZero<N> {
+    const OK: () = assert!(N != 0, "array must be non-empty");
+}
+
+/// Converts an array into a non-empty vector.
+///
+/// Fails to compile if the array is empty.
+/// # Examples
+/// ```
+/// # use non_empty_vec::{ne_vec, NonEmpty};
+/// assert_eq!(NonEmpty::from([1, 2, 3]), ne_vec![1, 2, 3]);
+/// ```
+/// ```compile_fail
+/// # use non_empty_vec::NonEmpty;
+/// let _ = NonEmpty::<i32>::from([]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> From<[T; N]> for NonEmpty<T> {
+    fn from(array: [T; N]) -> Self {
+        let () = AssertNonZero::<N>::OK;
+        Self(Vec::from(array))
+    }
+}
+
+/// Converts a non-empty vector into an array, giving back the vector if the length doesn't match.
+/// # Example
+/// ```
+/// # use non_empty_vec::ne_vec;
+/// # use core::convert::TryFrom;
+/// assert_eq!(<[_; 2]>::try_from(ne_vec![1, 2]), Ok([1, 2]));
+/// assert_eq!(<[_; 3]>::try_from(ne_vec![1, 2]), Err(ne_vec![1, 2]));
+/// ```
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryFrom<NonEmpty<T>> for [T; N] {
+    type Error = NonEmpty<T>;
+    fn try_from(v: NonEmpty<T>) -> Result<Self, Self::Error> {
+        <[T; N]>::try_from(v.0).map_err(NonEmpty)
+    }
+}
+
 /// Returns a unit-length vector containing the default element value.
+#[cfg(feature = "alloc")]
 impl<T: Default> Default for NonEmpty<T> {
     fn default() -> Self {
         ne_vec![T::default()]
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// The error returned when a collection is empty, or an operation would leave it empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct EmptyError;
 
+impl core::fmt::Display for EmptyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str("collection must not be empty")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for EmptyError {}
+
+/// The error returned when converting an empty container into a non-empty one.
+///
+/// This gives back the original container, so that nothing is lost,
+/// such as the capacity of an empty [`Vec`].
+/// # Example
+/// ```
+/// # use non_empty_vec::NonEmpty;
+/// # use core::convert::TryFrom;
+/// let v: Vec<i32> = Vec::with_capacity(16);
+/// let err = NonEmpty::try_from(v).unwrap_err();
+/// let v = err.into_inner();
+/// assert!(v.capacity() >= 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyContainerError<C>(C);
+
+impl<C> EmptyContainerError<C> {
+    /// Returns a reference to the empty container.
+    #[inline]
+    pub fn as_inner(&self) -> &C {
+        &self.0
+    }
+    /// Gives back the empty container.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+impl<C> core::fmt::Display for EmptyContainerError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&EmptyError, f)
+    }
+}
+#[cfg(feature = "std")]
+impl<C: core::fmt::Debug> std::error::Error for EmptyContainerError<C> {}
+
+impl<C> From<EmptyContainerError<C>> for EmptyError {
+    #[inline]
+    fn from(_: EmptyContainerError<C>) -> Self {
+        EmptyError
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<T> TryFrom<Vec<T>> for NonEmpty<T> {
-    type Error = EmptyError;
+    type Error = EmptyContainerError<Vec<T>>;
     fn try_from(xs: Vec<T>) -> Result<Self, Self::Error> {
         if xs.is_empty() {
-            Err(EmptyError)
+            Err(EmptyContainerError(xs))
         } else {
             Ok(NonEmpty(xs))
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> From<Box<NonEmptySlice<T>>> for NonEmpty<T> {
     #[inline]
     fn from(slice: Box<NonEmptySlice<T>>) -> Self {
@@ -132,15 +661,21 @@ impl<T> From<Box<NonEmptySlice<T>>> for NonEmpty<T> {
         unsafe { Self::new_unchecked(v) }
     }
 }
+#[cfg(feature = "alloc")]
 impl<T> TryFrom<Box<[T]>> for NonEmpty<T> {
-    type Error = EmptyError;
+    type Error = EmptyContainerError<Box<[T]>>;
     #[inline]
     fn try_from(value: Box<[T]>) -> Result<Self, Self::Error> {
-        let v = Vec::from(value);
-        Self::try_from(v)
+        if value.is_empty() {
+            Err(EmptyContainerError(value))
+        } else {
+            // SAFETY: We just checked that it's not empty.
+            Ok(unsafe { Self::new_unchecked(Vec::from(value)) })
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> ops::Deref for NonEmpty<T> {
     type Target = NonEmptySlice<T>;
     fn deref(&self) -> &Self::Target {
@@ -151,6 +686,7 @@ impl<T> ops::Deref for NonEmpty<T> {
         }
     }
 }
+#[cfg(feature = "alloc")]
 impl<T> ops::DerefMut for NonEmpty<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe {
@@ -161,24 +697,78 @@ impl<T> ops::DerefMut for NonEmpty<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> AsRef<[T]> for NonEmpty<T> {
     fn as_ref(&self) -> &[T] {
         self
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> AsMut<[T]> for NonEmpty<T> {
     fn as_mut(&mut self) -> &mut [T] {
         self.0.as_mut()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> AsRef<Vec<T>> for NonEmpty<T> {
     fn as_ref(&self) -> &Vec<T> {
         &self.0
     }
 }
 
+// `Hash`, `Eq` and `Ord` are all derived, so they behave the same as for the borrowed slices.
+#[cfg(feature = "alloc")]
+impl<T> Borrow<NonEmptySlice<T>> for NonEmpty<T> {
+    #[inline]
+    fn borrow(&self) -> &NonEmptySlice<T> {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> BorrowMut<NonEmptySlice<T>> for NonEmpty<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut NonEmptySlice<T> {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> Borrow<[T]> for NonEmpty<T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T> BorrowMut<[T]> for NonEmpty<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+/// Allows a [`NonEmptySlice`] to be used in a [`Cow`](alloc::borrow::Cow).
+/// # Example
+/// ```
+/// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptySlice};
+/// use std::borrow::Cow;
+///
+/// let v = ne_vec![1, 2, 3];
+/// let mut cow: Cow<NonEmptySlice<i32>> = Cow::Borrowed(&v);
+/// cow.to_mut().push(4);
+/// assert_eq!(cow.into_owned(), ne_vec![1, 2, 3, 4]);
+/// ```
+#[cfg(feature = "alloc")]
+impl<T: Clone> ToOwned for NonEmptySlice<T> {
+    type Owned = NonEmpty<T>;
+    #[inline]
+    fn to_owned(&self) -> NonEmpty<T> {
+        self.to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl<T, I: SliceIndex<[T]>> ops::Index<I> for NonEmpty<T> {
     type Output = I::Output;
 
@@ -187,6 +777,7 @@ impl<T, I: SliceIndex<[T]>> ops::Index<I> for NonEmpty<T> {
         ops::Index::index(self.as_slice(), index)
     }
 }
+#[cfg(feature = "alloc")]
 impl<T, I: SliceIndex<[T]>> ops::IndexMut<I> for NonEmpty<T> {
     #[inline]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
@@ -194,6 +785,7 @@ impl<T, I: SliceIndex<[T]>> ops::IndexMut<I> for NonEmpty<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> IntoIterator for NonEmpty<T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
@@ -202,6 +794,7 @@ impl<T> IntoIterator for NonEmpty<T> {
         self.0.into_iter()
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a, T> IntoIterator for &'a NonEmpty<T> {
     type Item = &'a T;
     type IntoIter = Iter<'a, T>;
@@ -210,6 +803,7 @@ impl<'a, T> IntoIterator for &'a NonEmpty<T> {
         self.iter()
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a, T> IntoIterator for &'a mut NonEmpty<T> {
     type Item = &'a mut T;
     type IntoIter = IterMut<'a, T>;
@@ -219,6 +813,7 @@ impl<'a, T> IntoIterator for &'a mut NonEmpty<T> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> NonEmpty<T> {
     /// Removes the specified range from the vector in bulk, returning the removed items as an iterator.
     /// # Panics
@@ -253,7 +848,7 @@ impl<T> NonEmpty<T> {
     /// v.drain(0..v.len().get());
     /// ```
     #[track_caller]
-    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> std::vec::Drain<T> {
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> alloc::vec::Drain<'_, T> {
         // whether or not there is space leftover in the start of the vector.
         let leftover_start = match range.start_bound() {
             core::ops::Bound::Included(&start) => start > 0,
@@ -278,50 +873,279 @@ impl<T> NonEmpty<T> {
 
     /// Calls a predicate with every element of this vector, removing each element for which the predicate returns `true`.
     /// All removed elements are yielded from the returned iterator.
+    ///
+    /// Retained elements are moved into place as the iterator advances, so draining runs in linear time.
+    /// If the returned iterator is dropped early, any elements that have not been examined are kept.
+    /// If it is leaked instead, the unexamined elements may be leaked along with it, but the vector is never left empty.
+    ///
+    /// If every element would be removed, the element chosen by `survivor` is left behind instead.
+    /// Each element is passed to the predicate at most once. With [`Survivor::MostRecent`], elements are
+    /// only passed to the predicate as they are visited. With the other policies, the first call to
+    /// `next` or `next_back` may run the predicate ahead on the elements that follow,
+    /// until it finds one that gets to stay.
     /// # Examples
     /// Normal use.
     /// ```
     /// // Filter out odd entries
-    /// # use non_empty_vec::ne_vec;
+    /// # use non_empty_vec::{ne_vec, Survivor};
     /// let mut v = ne_vec![1,2,3,4,5,6];
-    /// assert!(v.drain_filter(|i| *i % 2 == 1).eq([1, 3, 5]));
+    /// assert!(v.drain_filter(Survivor::MostRecent, |i| *i % 2 == 1).eq([1, 3, 5]));
     /// assert_eq!(v, ne_vec![2, 4, 6]);
     /// ```
     /// At least one element is always left behind.
     /// ```
-    /// // When there's only one element left, the predicate never even gets called on it.
-    /// # use non_empty_vec::ne_vec;
-    /// let mut v = ne_vec![1];
-    /// v.drain_filter(|_| unreachable!());
+    /// # use non_empty_vec::{ne_vec, Survivor};
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert!(v.drain_filter(Survivor::First, |_| true).eq([2, 3, 4]));
     /// assert_eq!(v, ne_vec![1]);
     ///
-    /// // This also applies if all elements before the final get removed.
-    /// let mut v = ne_vec![1, 2, 3, 4, 5];
-    /// let removed = v.drain_filter(|&mut i| if i < 5 {
-    ///     true
-    /// } else {
-    ///     unreachable!()
-    /// });
-    /// assert!(removed.eq(1..=4));
-    /// assert_eq!(v, ne_vec![5]);
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert!(v.drain_filter(Survivor::Last, |_| true).rev().eq([3, 2, 1]));
+    /// assert_eq!(v, ne_vec![4]);
+    ///
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert!(v.drain_filter(Survivor::MostRecent, |_| true).rev().eq([4, 3, 2]));
+    /// assert_eq!(v, ne_vec![1]);
+    ///
+    /// ```
+    /// With [`Survivor::Fail`], nothing is yielded if every element matches,
+    /// which can be checked with [`DrainFilter::result`].
+    /// ```
+    /// # use non_empty_vec::{ne_vec, EmptyError, Survivor};
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// let mut iter = v.drain_filter(Survivor::Fail, |_| true);
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.result(), Err(EmptyError));
+    /// drop(iter);
+    /// assert_eq!(v, ne_vec![1, 2, 3, 4]);
+    /// ```
+    /// The survivor only matters if every element matches.
+    /// ```
+    /// # use non_empty_vec::{ne_vec, Survivor};
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert!(v.drain_filter(Survivor::Fail, |i| *i != 3).eq([1, 2, 4]));
+    /// assert_eq!(v, ne_vec![3]);
     /// ```
     /// Lazy execution.
     /// ```
     /// // Nothing gets removed until the iterator is consumed
-    /// # use non_empty_vec::ne_vec;
+    /// # use non_empty_vec::{ne_vec, Survivor};
     /// let mut v = ne_vec![1,2,3,4];
-    /// v.drain_filter(|_| true);
+    /// v.drain_filter(Survivor::MostRecent, |_| true);
     /// assert_eq!(v, ne_vec![1,2,3,4]);
     /// ```
     #[inline]
-    pub fn drain_filter<F>(&mut self, f: F) -> DrainFilter<T, F>
+    pub fn drain_filter<F>(&mut self, survivor: Survivor, f: F) -> DrainFilter<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        DrainFilter::new(self, survivor, f)
+    }
+
+    /// Retains only the elements specified by the predicate, removing each element for which it returns `false`.
+    /// Elements are visited in order, exactly once.
+    ///
+    /// If the predicate rejects every element, the last element is kept and [`EmptyError`] is returned.
+    /// # Examples
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// assert!(v.retain(|i| i % 2 == 0).is_ok());
+    /// assert_eq!(v, ne_vec![2, 4]);
+    /// ```
+    /// Rejecting every element.
+    /// ```
+    /// # use non_empty_vec::{ne_vec, EmptyError};
+    /// let mut v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.retain(|_| false), Err(EmptyError));
+    /// assert_eq!(v, ne_vec![3]);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F) -> Result<(), EmptyError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|x| f(x))
+    }
+
+    /// Retains only the elements specified by the predicate, passing a mutable reference to each element.
+    ///
+    /// If the predicate rejects every element, the last element is kept and [`EmptyError`] is returned.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 3, 4];
+    /// v.retain_mut(|i| {
+    ///     *i += 1;
+    ///     *i % 2 == 0
+    /// })
+    /// .unwrap();
+    /// assert_eq!(v, ne_vec![2, 4]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F) -> Result<(), EmptyError>
     where
         F: FnMut(&mut T) -> bool,
     {
-        DrainFilter::new(self, f)
+        self.retain_mut_keeping(Survivor::Last, f)
+    }
+
+    /// Like [`retain`](#method.retain), but if the predicate rejects every element,
+    /// the element chosen by `survivor` is kept. [`EmptyError`] is still returned in that case.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, EmptyError, Survivor};
+    /// let mut v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.retain_keeping(Survivor::First, |_| false), Err(EmptyError));
+    /// assert_eq!(v, ne_vec![1]);
+    ///
+    /// let mut v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.retain_keeping(Survivor::Fail, |_| false), Err(EmptyError));
+    /// assert_eq!(v, ne_vec![1, 2, 3]);
+    /// ```
+    pub fn retain_keeping<F>(&mut self, survivor: Survivor, mut f: F) -> Result<(), EmptyError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut_keeping(survivor, |x| f(x))
     }
+
+    /// Like [`retain_mut`](#method.retain_mut), but if the predicate rejects every element,
+    /// the element chosen by `survivor` is kept. [`EmptyError`] is still returned in that case.
+    pub fn retain_mut_keeping<F>(&mut self, survivor: Survivor, mut f: F) -> Result<(), EmptyError>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let len = self.len().get();
+        let mut rejected = 0;
+        self.drain_filter(survivor, |x| {
+            let reject = !f(x);
+            rejected += reject as usize;
+            reject
+        })
+        .for_each(drop);
+        if rejected == len {
+            Err(EmptyError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Removes consecutive repeated elements in the vector according to [`PartialEq`].
+    /// This can never leave the vector empty.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![1, 2, 2, 3, 2];
+    /// v.dedup();
+    /// assert_eq!(v, ne_vec![1, 2, 3, 2]);
+    /// ```
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.0.dedup()
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that resolve to the same key.
+    /// This can never leave the vector empty.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec![10, 20, 21, 30, 20];
+    /// v.dedup_by_key(|i| *i / 10);
+    /// assert_eq!(v, ne_vec![10, 20, 30, 20]);
+    /// ```
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        self.0.dedup_by_key(key)
+    }
+
+    /// Splits the vector into runs of consecutive elements with equal keys,
+    /// pairing each run with its key.
+    ///
+    /// Both the list of groups and each group are non-empty.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let words = ne_vec!["apple", "avocado", "banana", "cherry", "cranberry"];
+    /// let groups = words.group_by_key(|w| w.chars().next());
+    /// assert_eq!(groups.len().get(), 3);
+    /// assert_eq!(groups.first(), &(Some('a'), ne_vec!["apple", "avocado"]));
+    /// assert_eq!(groups.last(), &(Some('c'), ne_vec!["cherry", "cranberry"]));
+    /// ```
+    pub fn group_by_key<K, F>(self, mut f: F) -> NonEmpty<(K, NonEmpty<T>)>
+    where
+        F: FnMut(&T) -> K,
+        K: PartialEq,
+    {
+        let (first, rest) = self.into_ne_iter().next();
+        let mut groups = Vec::new();
+        let mut key = f(&first);
+        let mut group = NonEmpty::new(first);
+        for x in rest {
+            let k = f(&x);
+            if k == key {
+                group.push(x);
+            } else {
+                let key = core::mem::replace(&mut key, k);
+                let group = core::mem::replace(&mut group, NonEmpty::new(x));
+                groups.push((key, group));
+            }
+        }
+        groups.push((key, group));
+        // SAFETY: We just pushed the last group.
+        unsafe { NonEmpty::new_unchecked(groups) }
+    }
+
+    /// Removes all but the first of consecutive elements in the vector satisfying a given equality relation.
+    /// This can never leave the vector empty.
+    ///
+    /// See [`Vec::dedup_by`] for the order in which the arguments are passed.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let mut v = ne_vec!["foo", "bar", "Bar", "baz", "bar"];
+    /// v.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(v, ne_vec!["foo", "bar", "baz", "bar"]);
+    /// ```
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.0.dedup_by(same_bucket)
+    }
+}
+
+/// Decides which element is left behind when a filtering operation such as
+/// [`NonEmpty::drain_filter`] or [`NonEmpty::retain_keeping`] would remove every element.
+///
+/// The predicate is still called at most once for each element. Except for [`MostRecent`](Survivor::MostRecent),
+/// the survivor can only be decided once it is known whether any element gets to stay, so the predicate
+/// may get called on elements ahead of the ones that have been yielded so far, until one is found.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Survivor {
+    /// Keep the first element of the vector.
+    First,
+    /// Keep the last element of the vector.
+    Last,
+    /// Keep the element that the predicate rejected most recently.
+    ///
+    /// This is decided lazily: if the only element left to visit gets rejected, and every other
+    /// element has been removed, it is kept. This is the last element when visiting from the front,
+    /// and the first when visiting from the back.
+    MostRecent,
+    /// Don't remove anything, leaving the vector unchanged.
+    /// [`NonEmpty::retain_keeping`] will return [`EmptyError`], and so will [`DrainFilter::result`].
+    Fail,
 }
 
+#[cfg(feature = "alloc")]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct DrainFilter<'a, T, F>
 where
@@ -330,28 +1154,341 @@ where
     vec: &'a mut NonEmpty<T>,
     f: F,
 
-    // Always `0 <= left <= right <= vec.len()`, usually `left < right`.
-    // When `left == right`, the iterator is complete.
-    left: usize,
-    right: usize,
-}
-impl<'a, T, F> DrainFilter<'a, T, F>
-where
-    F: FnMut(&mut T) -> bool,
-{
+    // While iterating, the buffer of `vec` is laid out as follows:
+    // * `[0, kept)` holds retained elements from the front, compacted.
+    // * `kept` holds the next element to be examined from the front, if `has_front`.
+    // * `[kept + 1, idx)` holds the holes left by yielded elements.
+    // * `[idx, end)` holds the rest of the unexamined elements.
+    // * `[end, back)` holds the holes left by elements yielded from the back.
+    // * `[back, old_len)` holds retained elements from the back, compacted.
+    //
+    // The length of `vec` is kept at `kept + has_front`, so that it only ever
+    // covers initialized elements and is never empty, even if this iterator gets leaked.
+    // `has_front` is only `false` once every element has been examined.
+    kept: usize,
+    has_front: bool,
+    idx: usize,
+    end: usize,
+    back: usize,
+    old_len: usize,
+
+    survivor: Survivor,
+    // Whether or not some element is known to be retained, so the survivor no longer matters.
+    resolved: bool,
+    // Whether or not every element matched, and nothing was removed because of `Survivor::Fail`.
+    failed: bool,
+    // Predicate results that were computed ahead of time while deciding the survivor.
+    // From the front, the next `matched_front` unexamined elements match, followed
+    // by an element that gets retained if `kept_front`. Likewise from the back.
+    // At most one end ever has results.
+    matched_front: usize,
+    kept_front: bool,
+    matched_back: usize,
+    kept_back: bool,
+}
+#[cfg(feature = "alloc")]
+impl<'a, T, F> DrainFilter<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    /// Creates an iterator that leaves behind the element chosen by `survivor`,
+    /// if every element matches the predicate.
+    pub fn new(vec: &'a mut NonEmpty<T>, survivor: Survivor, f: F) -> Self {
+        let old_len = vec.len().get();
+        // SAFETY: The vector is non-empty, so the first element is initialized.
+        // The remaining elements are tracked by this iterator until it gets dropped.
+        unsafe { vec.0.set_len(1) };
+        Self {
+            vec,
+            f,
+            kept: 0,
+            has_front: true,
+            idx: 1,
+            end: old_len,
+            back: old_len,
+            old_len,
+            survivor,
+            resolved: false,
+            failed: false,
+            matched_front: 0,
+            kept_front: false,
+            matched_back: 0,
+            kept_back: false,
+        }
+    }
+
+    /// Returns [`EmptyError`] if every element matched the predicate, so nothing was removed
+    /// because of [`Survivor::Fail`].
+    ///
+    /// This is only known once the iterator has been advanced, and is `Ok(())` until then.
+    #[inline]
+    pub fn result(&self) -> Result<(), EmptyError> {
+        if self.failed {
+            Err(EmptyError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The number of elements that have not been passed to the predicate yet.
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.has_front as usize + (self.end - self.idx)
+    }
+
+    /// Whether or not the next examined element may be removed, without leaving the vector empty.
+    #[inline]
+    fn can_remove(&self) -> bool {
+        let retained = self.kept + (self.old_len - self.back);
+        retained > 0 || self.remaining() > 1
+    }
+
+    /// Returns a pointer to the `i`th unexamined element, counting from the front.
+    ///
+    /// # Safety
+    /// `i` must be less than [`remaining`](#method.remaining).
+    #[inline]
+    unsafe fn unexamined(&mut self, i: usize) -> *mut T {
+        let base = self.vec.0.as_mut_ptr();
+        if i == 0 {
+            base.add(self.kept)
+        } else {
+            base.add(self.idx + i - 1)
+        }
+    }
+
+    /// Decides whether the next unexamined element from the front should be removed.
+    ///
+    /// # Safety
+    /// There must be at least one unexamined element.
+    unsafe fn matches_front(&mut self) -> bool {
+        if self.matched_front > 0 {
+            self.matched_front -= 1;
+            true
+        } else if self.kept_front {
+            self.kept_front = false;
+            false
+        } else if self.matched_back + self.kept_back as usize == self.remaining() {
+            // Only elements with results from the back are left, and this is the first of them.
+            if self.kept_back {
+                self.kept_back = false;
+                false
+            } else {
+                self.matched_back -= 1;
+                true
+            }
+        } else {
+            let cur = self.unexamined(0);
+            (self.f)(&mut *cur)
+        }
+    }
+    /// Decides whether the next unexamined element from the back should be removed.
+    ///
+    /// # Safety
+    /// There must be at least one unexamined element.
+    unsafe fn matches_back(&mut self) -> bool {
+        if self.matched_back > 0 {
+            self.matched_back -= 1;
+            true
+        } else if self.kept_back {
+            self.kept_back = false;
+            false
+        } else if self.matched_front + self.kept_front as usize == self.remaining() {
+            // Only elements with results from the front are left, and this is the last of them.
+            if self.kept_front {
+                self.kept_front = false;
+                false
+            } else {
+                self.matched_front -= 1;
+                true
+            }
+        } else {
+            let cur = self.unexamined(self.remaining() - 1);
+            (self.f)(&mut *cur)
+        }
+    }
+
+    /// Removes the next unexamined element from the front if `matched`, or retains it otherwise.
+    ///
+    /// # Safety
+    /// There must be at least one unexamined element.
+    unsafe fn take_front(&mut self, matched: bool) -> Option<T> {
+        let base = self.vec.0.as_mut_ptr();
+        let cur = base.add(self.kept);
+        let item = if matched {
+            // SAFETY: The element is initialized, and its slot
+            // gets treated as a hole from now on.
+            Some(core::ptr::read(cur))
+        } else {
+            self.kept += 1;
+            self.resolved = true;
+            None
+        };
+        // Move the next unexamined element right after the retained ones.
+        if self.idx < self.end {
+            core::ptr::copy(base.add(self.idx), base.add(self.kept), 1);
+            self.idx += 1;
+        } else {
+            self.has_front = false;
+        }
+        self.sync_len();
+        item
+    }
+    /// Removes the next unexamined element from the back if `matched`, or retains it otherwise.
+    ///
+    /// # Safety
+    /// There must be at least one unexamined element.
+    unsafe fn take_back(&mut self, matched: bool) -> Option<T> {
+        // The only unexamined element left is the one at the front.
+        if self.idx == self.end {
+            self.has_front = false;
+            let cur = self.vec.0.as_mut_ptr().add(self.kept);
+            let item = if matched {
+                Some(core::ptr::read(cur))
+            } else {
+                self.kept += 1;
+                self.resolved = true;
+                None
+            };
+            self.sync_len();
+            return item;
+        }
+        let base = self.vec.0.as_mut_ptr();
+        let cur = base.add(self.end - 1);
+        self.end -= 1;
+        if matched {
+            Some(core::ptr::read(cur))
+        } else {
+            self.back -= 1;
+            self.resolved = true;
+            core::ptr::copy(cur, base.add(self.back), 1);
+            None
+        }
+    }
+
+    /// Calls the predicate on unexamined elements, starting from the front (or back),
+    /// until an element is found that gets retained. If every element matches,
+    /// the survivor is chosen according to `self.survivor`.
+    ///
+    /// # Safety
+    /// There must be at least one unexamined element, no element may be retained,
+    /// and no predicate results may be known yet.
+    unsafe fn resolve(&mut self, survivor: Survivor, from_back: bool) {
+        let remaining = self.remaining();
+        // Record the results as we go, so they are not lost if the predicate panics.
+        while self.matched_front + self.matched_back < remaining {
+            let i = self.matched_front + self.matched_back;
+            let cur = self.unexamined(if from_back { remaining - 1 - i } else { i });
+            if !(self.f)(&mut *cur) {
+                if from_back {
+                    self.kept_back = true;
+                } else {
+                    self.kept_front = true;
+                }
+                self.resolved = true;
+                return;
+            }
+            if from_back {
+                self.matched_back += 1;
+            } else {
+                self.matched_front += 1;
+            }
+        }
+        // Every element matched, so one has to stay behind.
+        self.resolved = true;
+        let (near, far) = if from_back {
+            (Survivor::Last, Survivor::First)
+        } else {
+            (Survivor::First, Survivor::Last)
+        };
+        match survivor {
+            Survivor::Fail => {
+                self.failed = true;
+                self.matched_front = 0;
+                self.matched_back = 0;
+                self.restore();
+            }
+            s if s == near => {
+                // Retain the element that was examined first.
+                if from_back {
+                    self.matched_back -= 1;
+                    self.take_back(false);
+                } else {
+                    self.matched_front -= 1;
+                    self.take_front(false);
+                }
+            }
+            s => {
+                // Retain the element that was examined last.
+                debug_assert!(s == far);
+                if from_back {
+                    self.matched_back -= 1;
+                    self.kept_back = true;
+                } else {
+                    self.matched_front -= 1;
+                    self.kept_front = true;
+                }
+            }
+        }
+    }
+
+    /// Whether or not another element may be examined,
+    /// deciding the survivor first if that's necessary.
+    #[inline]
+    fn ready(&mut self, from_back: bool) -> bool {
+        if !self.has_front {
+            return false;
+        }
+        match self.survivor {
+            _ if self.resolved => true,
+            // Decided as each element is examined, see `next` and `next_back`.
+            Survivor::MostRecent => true,
+            survivor => {
+                // SAFETY: Nothing has been retained or yielded yet, so there are no known results,
+                // and `has_front` means that there is at least one unexamined element.
+                unsafe { self.resolve(survivor, from_back) };
+                self.has_front
+            }
+        }
+    }
+
+    /// Updates the length of the vector to cover the elements at the front of the buffer.
+    /// Once every element has been examined, the retained elements get moved back together.
     #[inline]
-    pub fn new(vec: &'a mut NonEmpty<T>, f: F) -> Self {
-        let left = 0;
-        let right = vec.len().get();
-        Self {
-            vec,
-            f,
-            left,
-            right,
+    fn sync_len(&mut self) {
+        if self.has_front {
+            // SAFETY: `[0, kept]` only holds initialized elements.
+            unsafe { self.vec.0.set_len(self.kept + 1) };
+        } else {
+            self.restore();
+        }
+    }
+
+    /// Moves all of the unexamined and retained elements back together,
+    /// and restores the length of the vector.
+    fn restore(&mut self) {
+        unsafe {
+            let base = self.vec.0.as_mut_ptr();
+            let mut len = self.kept + self.has_front as usize;
+            // SAFETY: Both of the moved ranges hold initialized elements, and each
+            // destination is at or before its source, so nothing gets overwritten.
+            let unexamined = self.end - self.idx;
+            core::ptr::copy(base.add(self.idx), base.add(len), unexamined);
+            len += unexamined;
+            let retained_back = self.old_len - self.back;
+            core::ptr::copy(base.add(self.back), base.add(len), retained_back);
+            len += retained_back;
+            self.vec.0.set_len(len);
         }
+        // Mark every element as retained so that restoring again is a no-op.
+        self.kept = self.vec.0.len();
+        self.has_front = false;
+        self.idx = self.end;
+        self.back = self.old_len;
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<'a, T, F> Iterator for DrainFilter<'a, T, F>
 where
     F: FnMut(&mut T) -> bool,
@@ -359,70 +1496,67 @@ where
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
         // Loop until either we find an element or the list is depleted.
-        loop {
-            // Only try draining this element if there would be more elements leftover.
-            let any_yielded = self.left > 0 || self.right < self.vec.0.len();
-            if (any_yielded || self.right - self.left > 1) && self.left < self.right {
-                // If the elment passes the predicate, remove it and yield it.
-                if (self.f)(&mut self.vec[self.left]) {
-                    let item = self.vec.0.remove(self.left);
-                    self.right -= 1;
-                    break Some(item);
-                }
-                // If it doesn't pass, leave the element and repeat the loop.
-                else {
-                    self.left += 1;
+        // Only try draining an element if there would be more elements leftover.
+        while self.ready(false) {
+            // SAFETY: `ready` ensures that there is an unexamined element.
+            // If the predicate panics, the element is still accounted for as unexamined.
+            unsafe {
+                // The final element gets retained if nothing else was, as required by `Survivor::MostRecent`.
+                // Other policies already made sure that some element is retained.
+                let matched = self.matches_front() && self.can_remove();
+                if let Some(item) = self.take_front(matched) {
+                    return Some(item);
                 }
             }
-            // We've reached the point where we only have one element left, so leave it.
-            else {
-                break None;
-            }
         }
+        None
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let max = self.right - self.left;
-        (0, Some(max))
+        (0, Some(self.remaining()))
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a, T, F> DoubleEndedIterator for DrainFilter<'a, T, F>
 where
     F: FnMut(&mut T) -> bool,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         // Loop until either we find an element or the list is depleted.
-        loop {
-            // Only try draining this element if there would be more elements leftover.
-            let any_yielded = self.right < self.vec.0.len() || self.left > 0;
-            if (any_yielded || self.right - self.left > 1) && self.right > self.left {
-                // If the elment passes the predicate, remove it and yield it.
-                if (self.f)(&mut self.vec[self.right - 1]) {
-                    let item = self.vec.0.remove(self.right - 1);
-                    self.right -= 1;
-                    break Some(item);
-                }
-                // If it doesn't pass, leave the element and repeat the loop.
-                else {
-                    self.right -= 1;
+        // Only try draining an element if there would be more elements leftover.
+        while self.ready(true) {
+            // SAFETY: `ready` ensures that there is an unexamined element.
+            unsafe {
+                let matched = self.matches_back() && self.can_remove();
+                if let Some(item) = self.take_back(matched) {
+                    return Some(item);
                 }
             }
-            // We've reached the point where we only have one element left, so leave it.
-            else {
-                break None;
-            }
         }
+        None
     }
 }
+#[cfg(feature = "alloc")]
 impl<'a, T, F> FusedIterator for DrainFilter<'a, T, F> where F: FnMut(&mut T) -> bool {}
 
-#[cfg(feature = "serde")]
+#[cfg(feature = "alloc")]
+impl<'a, T, F> Drop for DrainFilter<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // Any elements that were not examined yet are kept in the vector.
+        self.restore();
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
 impl<T: Serialize> Serialize for NonEmpty<T> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         self.as_slice().serialize(serializer)
     }
 }
 
-#[cfg(feature = "serde")]
+#[cfg(all(feature = "serde", feature = "alloc"))]
 impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmpty<T> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Self::try_from(<Vec<T>>::deserialize(deserializer)?)
@@ -483,6 +1617,7 @@ impl<T> NonEmptySlice<T> {
     /// # Safety
     /// Ensure that the input slice is not empty.
     #[inline]
+    #[cfg(feature = "alloc")]
     pub unsafe fn unchecked_boxed(slice: Box<[T]>) -> Box<Self> {
         debug_assert!(!slice.is_empty());
         // SAFETY: This type is `repr(transparent)`, so we can safely
@@ -545,6 +1680,7 @@ impl<T> NonEmptySlice<T> {
     }
     /// Creates a new `NonEmptySlice` from a primitive slice. Returns [`None`] if the slice is empty.
     #[inline]
+    #[cfg(feature = "alloc")]
     pub fn from_boxed_slice(slice: Box<[T]>) -> Option<Box<Self>> {
         if !slice.is_empty() {
             // SAFETY: We just checked that it's not empty,
@@ -567,6 +1703,7 @@ impl<T> NonEmptySlice<T> {
     }
     /// Converts this `NonEmptySlice` into a primitive boxed slice.
     #[inline]
+    #[cfg(feature = "alloc")]
     pub fn into_boxed_slice(self: Box<Self>) -> Box<[T]> {
         // SAFETY: This type is `repr(transparent)`, so we can
         // safely cast the pointer like this.
@@ -574,6 +1711,132 @@ impl<T> NonEmptySlice<T> {
         unsafe { Box::from_raw(ptr) }
     }
 
+    /// Creates a reference-counted `NonEmptySlice` without checking the length.
+    /// # Safety
+    /// Ensure that the input slice is not empty.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub unsafe fn unchecked_rc(slice: Rc<[T]>) -> Rc<Self> {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the pointers like this.
+        let ptr = Rc::into_raw(slice) as *const Self;
+        Rc::from_raw(ptr)
+    }
+    /// Creates an atomically reference-counted `NonEmptySlice` without checking the length.
+    /// # Safety
+    /// Ensure that the input slice is not empty.
+    #[inline]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub unsafe fn unchecked_arc(slice: Arc<[T]>) -> Arc<Self> {
+        debug_assert!(!slice.is_empty());
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the pointers like this.
+        let ptr = Arc::into_raw(slice) as *const Self;
+        Arc::from_raw(ptr)
+    }
+    /// Creates a reference-counted `NonEmptySlice` from a primitive one.
+    /// Gives back the input if it is empty.
+    ///
+    /// This can't be a [`TryFrom`] implementation, since neither type is local to this crate.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptySlice;
+    /// use std::rc::Rc;
+    ///
+    /// let s = NonEmptySlice::from_rc_slice(Rc::from([1, 2])).unwrap();
+    /// assert_eq!(s.first(), &1);
+    /// assert!(NonEmptySlice::<i32>::from_rc_slice(Rc::from([])).is_err());
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn from_rc_slice(slice: Rc<[T]>) -> Result<Rc<Self>, EmptyContainerError<Rc<[T]>>> {
+        if !slice.is_empty() {
+            // SAFETY: We just checked that it's not empty.
+            unsafe { Ok(Self::unchecked_rc(slice)) }
+        } else {
+            Err(EmptyContainerError(slice))
+        }
+    }
+    /// Creates an atomically reference-counted `NonEmptySlice` from a primitive one.
+    /// Gives back the input if it is empty.
+    #[inline]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub fn from_arc_slice(slice: Arc<[T]>) -> Result<Arc<Self>, EmptyContainerError<Arc<[T]>>> {
+        if !slice.is_empty() {
+            // SAFETY: We just checked that it's not empty.
+            unsafe { Ok(Self::unchecked_arc(slice)) }
+        } else {
+            Err(EmptyContainerError(slice))
+        }
+    }
+    /// Converts this `NonEmptySlice` into a primitive reference-counted slice.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn into_rc_slice(self: Rc<Self>) -> Rc<[T]> {
+        // SAFETY: This type is `repr(transparent)`, so we can
+        // safely cast the pointer like this.
+        let ptr = Rc::into_raw(self) as *const [T];
+        unsafe { Rc::from_raw(ptr) }
+    }
+    /// Converts this `NonEmptySlice` into a primitive atomically reference-counted slice.
+    #[inline]
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub fn into_arc_slice(self: Arc<Self>) -> Arc<[T]> {
+        // SAFETY: This type is `repr(transparent)`, so we can
+        // safely cast the pointer like this.
+        let ptr = Arc::into_raw(self) as *const [T];
+        unsafe { Arc::from_raw(ptr) }
+    }
+
+    /// Returns a mutable reference to the slice behind an [`Rc`],
+    /// cloning the elements into a new allocation first if the slice is shared.
+    ///
+    /// This works like [`Rc::make_mut`], but does not require the pointee to be sized.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptySlice};
+    /// use std::rc::Rc;
+    ///
+    /// let mut a: Rc<NonEmptySlice<i32>> = Rc::from(ne_vec![1, 2]);
+    /// let b = Rc::clone(&a);
+    /// *NonEmptySlice::make_mut_rc(&mut a).first_mut() = 0;
+    /// assert_eq!(*a, [0, 2]);
+    /// assert_eq!(*b, [1, 2]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn make_mut_rc(this: &mut Rc<Self>) -> &mut Self
+    where
+        T: Clone,
+    {
+        if Rc::get_mut(this).is_none() {
+            *this = Rc::from(&**this);
+        }
+        match Rc::get_mut(this) {
+            Some(slice) => slice,
+            // SAFETY: `this` was just replaced with a unique pointer.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+    /// Returns a mutable reference to the slice behind an [`Arc`],
+    /// cloning the elements into a new allocation first if the slice is shared.
+    ///
+    /// This works like [`Arc::make_mut`], but does not require the pointee to be sized.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    pub fn make_mut_arc(this: &mut Arc<Self>) -> &mut Self
+    where
+        T: Clone,
+    {
+        if Arc::get_mut(this).is_none() {
+            *this = Arc::from(&**this);
+        }
+        match Arc::get_mut(this) {
+            Some(slice) => slice,
+            // SAFETY: `this` was just replaced with a unique pointer.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
     /// Returns the length of this slice.
     #[inline]
     pub const fn len(&self) -> NonZeroUsize {
@@ -596,6 +1859,121 @@ impl<T> NonEmptySlice<T> {
         self.0.as_mut_ptr()
     }
 
+    /// Returns a [non-empty iterator](NonEmptyIterator) over the elements of this slice.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 2, 3];
+    /// let pairs: NonEmpty<_> = v.ne_iter().enumerate().collect();
+    /// assert_eq!(pairs, ne_vec![(0, &1), (1, &2), (2, &3)]);
+    /// ```
+    #[inline]
+    pub fn ne_iter(&self) -> iter::Iter<'_, T> {
+        iter::Iter { slice: self }
+    }
+    /// Returns a [non-empty iterator](NonEmptyIterator) that allows modifying each element of this slice.
+    #[inline]
+    pub fn ne_iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        iter::IterMut { slice: self }
+    }
+
+    /// Returns a [non-empty iterator](NonEmptyIterator) over chunks of `size` elements, starting at
+    /// the beginning of the slice. The last chunk may be shorter. See [`slice::chunks`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyIterator};
+    /// # use core::num::NonZeroUsize;
+    /// let v = ne_vec![1, 2, 3, 4, 5];
+    /// let size = NonZeroUsize::new(2).unwrap();
+    /// assert_eq!(v.chunks_nz(size).first(), &[1, 2]);
+    /// let lasts: Vec<_> = v.chunks_nz(size).map(|c| *c.last()).into_iter().collect();
+    /// assert_eq!(lasts, [2, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn chunks_nz(&self, size: NonZeroUsize) -> iter::Chunks<'_, T> {
+        iter::Chunks { slice: self, size }
+    }
+    /// Returns a [non-empty iterator](NonEmptyIterator) over mutable chunks of `size` elements,
+    /// starting at the beginning of the slice. The last chunk may be shorter. See [`slice::chunks_mut`].
+    #[inline]
+    pub fn chunks_mut_nz(&mut self, size: NonZeroUsize) -> iter::ChunksMut<'_, T> {
+        iter::ChunksMut { slice: self, size }
+    }
+    /// Returns a [non-empty iterator](NonEmptyIterator) over chunks of `size` elements, starting at
+    /// the end of the slice. The last chunk may be shorter. See [`slice::rchunks`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyIterator};
+    /// # use core::num::NonZeroUsize;
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.rchunks_nz(NonZeroUsize::new(2).unwrap()).first(), &[2, 3]);
+    /// ```
+    #[inline]
+    pub fn rchunks_nz(&self, size: NonZeroUsize) -> iter::RChunks<'_, T> {
+        iter::RChunks { slice: self, size }
+    }
+    /// Returns a [non-empty iterator](NonEmptyIterator) over mutable chunks of `size` elements,
+    /// starting at the end of the slice. The last chunk may be shorter. See [`slice::rchunks_mut`].
+    #[inline]
+    pub fn rchunks_mut_nz(&mut self, size: NonZeroUsize) -> iter::RChunksMut<'_, T> {
+        iter::RChunksMut { slice: self, size }
+    }
+    /// Returns a [non-empty iterator](NonEmptyIterator) over runs of elements,
+    /// splitting between each pair of neighbours for which `pred` returns `false`.
+    /// See [`slice::chunk_by`].
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmpty, NonEmptyIterator};
+    /// let v = ne_vec![1, 1, 2, 3, 3, 3];
+    /// let runs: NonEmpty<_> = v.chunk_by(|a, b| a == b).map(|run| (*run.first(), run.len().get())).collect();
+    /// assert_eq!(runs, ne_vec![(1, 2), (2, 1), (3, 3)]);
+    /// ```
+    #[inline]
+    pub fn chunk_by<P>(&self, pred: P) -> iter::ChunkBy<'_, T, P>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        iter::ChunkBy { slice: self, pred }
+    }
+
+    /// Returns an iterator over chunks of exactly `size` elements, starting at the beginning of the slice.
+    /// See [`slice::chunks_exact`].
+    ///
+    /// This is not a [non-empty iterator](NonEmptyIterator), since it yields nothing if `size`
+    /// is greater than the length of the slice.
+    #[inline]
+    pub fn chunks_exact_nz(&self, size: NonZeroUsize) -> iter::ChunksExact<'_, T> {
+        self.0.chunks_exact(size.get()).map(iter::ne_chunk)
+    }
+    /// Returns an iterator over mutable chunks of exactly `size` elements, starting at the beginning
+    /// of the slice. See [`slice::chunks_exact_mut`].
+    ///
+    /// This is not a [non-empty iterator](NonEmptyIterator), since it yields nothing if `size`
+    /// is greater than the length of the slice.
+    #[inline]
+    pub fn chunks_exact_mut_nz(&mut self, size: NonZeroUsize) -> iter::ChunksExactMut<'_, T> {
+        self.0.chunks_exact_mut(size.get()).map(iter::ne_chunk_mut)
+    }
+    /// Returns an iterator over all overlapping windows of `size` elements. See [`slice::windows`].
+    ///
+    /// This is not a [non-empty iterator](NonEmptyIterator), since it yields nothing if `size`
+    /// is greater than the length of the slice.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// # use core::num::NonZeroUsize;
+    /// let v = ne_vec![1, 2, 3];
+    /// let sums: Vec<i32> = v
+    ///     .windows_nz(NonZeroUsize::new(2).unwrap())
+    ///     .map(|w| w.first() + w.last())
+    ///     .collect();
+    /// assert_eq!(sums, [3, 5]);
+    /// ```
+    #[inline]
+    pub fn windows_nz(&self, size: NonZeroUsize) -> iter::Windows<'_, T> {
+        self.0.windows(size.get()).map(iter::ne_chunk)
+    }
+
     /// Returns a reference to the first element of this slice.
     /// # Example
     /// ```
@@ -746,6 +2124,323 @@ impl<T> NonEmptySlice<T> {
             unsafe { unreachable_unchecked!() }
         }
     }
+
+    /// Returns a reference to this slice as an array, or `None` if the length is not `N`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.as_array::<3>(), Some(&[1, 2, 3]));
+    /// assert_eq!(v.as_array::<2>(), None);
+    /// ```
+    #[inline]
+    pub fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
+        <&[T; N]>::try_from(self.as_slice()).ok()
+    }
+    /// Returns a mutable reference to this slice as an array, or `None` if the length is not `N`.
+    #[inline]
+    pub fn as_mut_array<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        <&mut [T; N]>::try_from(self.as_mut_slice()).ok()
+    }
+
+    /// Returns the first element of this slice as an array of length 1.
+    ///
+    /// Unlike [`first_chunk`](slice::first_chunk), this can't fail, since the slice is non-empty.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1, 2, 3];
+    /// assert_eq!(v.first_chunk1(), &[1]);
+    /// assert_eq!(v.last_chunk1(), &[3]);
+    /// ```
+    #[inline]
+    pub fn first_chunk1(&self) -> &[T; 1] {
+        core::array::from_ref(self.first())
+    }
+    /// Returns the first element of this slice as a mutable array of length 1.
+    #[inline]
+    pub fn first_chunk1_mut(&mut self) -> &mut [T; 1] {
+        core::array::from_mut(self.first_mut())
+    }
+    /// Returns the last element of this slice as an array of length 1.
+    ///
+    /// Unlike [`last_chunk`](slice::last_chunk), this can't fail, since the slice is non-empty.
+    #[inline]
+    pub fn last_chunk1(&self) -> &[T; 1] {
+        core::array::from_ref(self.last())
+    }
+    /// Returns the last element of this slice as a mutable array of length 1.
+    #[inline]
+    pub fn last_chunk1_mut(&mut self) -> &mut [T; 1] {
+        core::array::from_mut(self.last_mut())
+    }
+
+    /// Copies this slice into a new [non-empty vector](NonEmpty).
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptySlice};
+    /// let s = NonEmptySlice::from_ref(&1);
+    /// assert_eq!(s.to_vec(), ne_vec![1]);
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(&self) -> NonEmpty<T>
+    where
+        T: Clone,
+    {
+        // SAFETY: The copy has the same length as this slice.
+        unsafe { NonEmpty::new_unchecked(self.0.to_vec()) }
+    }
+
+    /// Returns the maximum element of this slice.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// Since [`NonEmpty`] implements [`Ord`], calling `max` on a vector resolves to [`Ord::max`],
+    /// so the vector has to be borrowed as a `&NonEmptySlice` first.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptySlice};
+    /// let v = ne_vec![1, 5, 3];
+    /// let s: &NonEmptySlice<_> = &v;
+    /// assert_eq!(s.max(), &5);
+    /// ```
+    #[inline]
+    pub fn max(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.max_by(T::cmp)
+    }
+    /// Returns the element that gives the maximum value with respect to the specified comparison function.
+    /// If several elements are equally maximum, the last one is returned.
+    #[inline]
+    pub fn max_by<F>(&self, mut compare: F) -> &T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.reduce(|a, b| match compare(a, b) {
+            Ordering::Greater => a,
+            _ => b,
+        })
+    }
+    /// Returns the element that gives the maximum value from the specified function.
+    /// If several elements are equally maximum, the last one is returned.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec!["a", "bbb", "cc", "ddd"];
+    /// assert_eq!(v.max_by_key(|s| s.len()), &"ddd");
+    /// ```
+    pub fn max_by_key<K, F>(&self, mut f: F) -> &T
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let (first, rest) = self.split_first();
+        let mut max = (f(first), first);
+        for x in rest {
+            let key = f(x);
+            if key >= max.0 {
+                max = (key, x);
+            }
+        }
+        max.1
+    }
+
+    /// Returns the minimum element of this slice.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// Like with [`max`](#method.max), a vector has to be borrowed as a `&NonEmptySlice` before calling this.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptySlice};
+    /// let v: &NonEmptySlice<_> = &ne_vec![4, 1, 3];
+    /// assert_eq!(v.min(), &1);
+    /// ```
+    #[inline]
+    pub fn min(&self) -> &T
+    where
+        T: Ord,
+    {
+        self.min_by(T::cmp)
+    }
+    /// Returns the element that gives the minimum value with respect to the specified comparison function.
+    /// If several elements are equally minimum, the first one is returned.
+    #[inline]
+    pub fn min_by<F>(&self, mut compare: F) -> &T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.reduce(|a, b| match compare(a, b) {
+            Ordering::Greater => b,
+            _ => a,
+        })
+    }
+    /// Returns the element that gives the minimum value from the specified function.
+    /// If several elements are equally minimum, the first one is returned.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![-3_i32, 1, 2, -1];
+    /// assert_eq!(v.min_by_key(|i| i.abs()), &1);
+    /// ```
+    pub fn min_by_key<K, F>(&self, mut f: F) -> &T
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let (first, rest) = self.split_first();
+        let mut min = (f(first), first);
+        for x in rest {
+            let key = f(x);
+            if key < min.0 {
+                min = (key, x);
+            }
+        }
+        min.1
+    }
+
+    /// Returns both the minimum and the maximum element of this slice, in a single pass.
+    /// Ties are broken like in [`min`](#method.min) and [`max`](#method.max).
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![3, 1, 4, 1, 5];
+    /// assert_eq!(v.minmax(), (&1, &5));
+    /// ```
+    pub fn minmax(&self) -> (&T, &T)
+    where
+        T: Ord,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold((first, first), |(min, max), x| {
+            (
+                if x < min { x } else { min },
+                if x >= max { x } else { max },
+            )
+        })
+    }
+
+    /// Returns the index of the maximum element of this slice.
+    /// If several elements are equally maximum, the index of the last one is returned.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1, 5, 3, 5];
+    /// assert_eq!(v.position_max(), 3);
+    /// ```
+    pub fn position_max(&self) -> usize
+    where
+        T: Ord,
+    {
+        let mut max = 0;
+        for (i, x) in self.iter().enumerate().skip(1) {
+            if x >= &self[max] {
+                max = i;
+            }
+        }
+        max
+    }
+    /// Returns the index of the minimum element of this slice.
+    /// If several elements are equally minimum, the index of the first one is returned.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![3, 1, 4, 1];
+    /// assert_eq!(v.position_min(), 1);
+    /// ```
+    pub fn position_min(&self) -> usize
+    where
+        T: Ord,
+    {
+        let mut min = 0;
+        for (i, x) in self.iter().enumerate().skip(1) {
+            if x < &self[min] {
+                min = i;
+            }
+        }
+        min
+    }
+
+    /// Reduces the elements of this slice to a single one, by repeatedly applying a reducing operation.
+    /// Unlike [`Iterator::reduce`], this always has a result.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec!["apple", "fig", "banana"];
+    /// assert_eq!(v.reduce(|a, b| if b.len() < a.len() { b } else { a }), &"fig");
+    /// ```
+    #[inline]
+    pub fn reduce<'a, F>(&'a self, f: F) -> &'a T
+    where
+        F: FnMut(&'a T, &'a T) -> &'a T,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(first, f)
+    }
+    /// Folds every element into an accumulator, which is initialized from the first element.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec![1, 2, 3];
+    /// let s = v.fold1(|x| x.to_string(), |acc, x| format!("{}-{}", acc, x));
+    /// assert_eq!(s, "1-2-3");
+    /// ```
+    #[inline]
+    pub fn fold1<B, I, F>(&self, init: I, f: F) -> B
+    where
+        I: FnOnce(&T) -> B,
+        F: FnMut(B, &T) -> B,
+    {
+        let (first, rest) = self.split_first();
+        rest.iter().fold(init(first), f)
+    }
+
+    /// Converts each element of this slice by reference, producing a new non-empty vector.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::ne_vec;
+    /// let v = ne_vec!["a", "bb", "ccc"];
+    /// assert_eq!(v.map_ref(|s| s.len()), ne_vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn map_ref<U, F>(&self, f: F) -> NonEmpty<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let v = self.iter().map(f).collect();
+        // SAFETY: Mapping preserves the length, so the vector is non-empty.
+        unsafe { NonEmpty::new_unchecked(v) }
+    }
+}
+
+/// Converts a reference to an array into a non-empty slice.
+///
+/// Fails to compile if the array is empty.
+/// # Examples
+/// ```
+/// # use non_empty_vec::NonEmptySlice;
+/// let s: &NonEmptySlice<_> = From::from(&[1, 2, 3]);
+/// assert_eq!(s.last(), &3);
+/// ```
+/// ```compile_fail
+/// # use non_empty_vec::NonEmptySlice;
+/// let _: &NonEmptySlice<i32> = From::from(&[]);
+/// ```
+impl<'a, T, const N: usize> From<&'a [T; N]> for &'a NonEmptySlice<T> {
+    fn from(array: &'a [T; N]) -> Self {
+        let () = AssertNonZero::<N>::OK;
+        // SAFETY: `N` is non-zero.
+        unsafe { NonEmptySlice::unchecked(array) }
+    }
+}
+impl<'a, T, const N: usize> From<&'a mut [T; N]> for &'a mut NonEmptySlice<T> {
+    fn from(array: &'a mut [T; N]) -> Self {
+        let () = AssertNonZero::<N>::OK;
+        // SAFETY: `N` is non-zero.
+        unsafe { NonEmptySlice::unchecked_mut(array) }
+    }
 }
 
 impl<'a, T> TryFrom<&'a [T]> for &'a NonEmptySlice<T> {
@@ -760,10 +2455,57 @@ impl<'a, T> TryFrom<&'a mut [T]> for &'a mut NonEmptySlice<T> {
         NonEmptySlice::from_mut_slice(value).ok_or(EmptyError)
     }
 }
+#[cfg(feature = "alloc")]
+impl<T> From<NonEmpty<T>> for Rc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        // SAFETY: The vector is non-empty.
+        unsafe { NonEmptySlice::unchecked_rc(Rc::from(v.0)) }
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: Clone> From<&NonEmptySlice<T>> for Rc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(slice: &NonEmptySlice<T>) -> Self {
+        // SAFETY: The slice is non-empty.
+        unsafe { NonEmptySlice::unchecked_rc(Rc::from(slice.as_slice())) }
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> From<NonEmpty<T>> for Arc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        // SAFETY: The vector is non-empty.
+        unsafe { NonEmptySlice::unchecked_arc(Arc::from(v.0)) }
+    }
+}
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T: Clone> From<&NonEmptySlice<T>> for Arc<NonEmptySlice<T>> {
+    #[inline]
+    fn from(slice: &NonEmptySlice<T>) -> Self {
+        // SAFETY: The slice is non-empty.
+        unsafe { NonEmptySlice::unchecked_arc(Arc::from(slice.as_slice())) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Clone for Box<NonEmptySlice<T>> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.to_vec().into_boxed_slice()
+    }
+}
+#[cfg(feature = "alloc")]
 impl<T> TryFrom<Box<[T]>> for Box<NonEmptySlice<T>> {
-    type Error = EmptyError;
+    type Error = EmptyContainerError<Box<[T]>>;
     fn try_from(value: Box<[T]>) -> Result<Self, Self::Error> {
-        NonEmptySlice::from_boxed_slice(value).ok_or(EmptyError)
+        if value.is_empty() {
+            Err(EmptyContainerError(value))
+        } else {
+            // SAFETY: We just checked that it's not empty.
+            Ok(unsafe { NonEmptySlice::unchecked_boxed(value) })
+        }
     }
 }
 
@@ -794,8 +2536,8 @@ impl<T> AsMut<[T]> for NonEmptySlice<T> {
     }
 }
 
-impl<T: std::fmt::Debug> std::fmt::Debug for NonEmptySlice<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl<T: core::fmt::Debug> core::fmt::Debug for NonEmptySlice<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{:?}", &self.0)
     }
 }
@@ -814,13 +2556,13 @@ impl<T: PartialEq> PartialEq<NonEmptySlice<T>> for [T] {
 }
 impl<T: PartialOrd, U: ?Sized + AsRef<[T]>> PartialOrd<U> for NonEmptySlice<T> {
     #[inline]
-    fn partial_cmp(&self, other: &U) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &U) -> Option<core::cmp::Ordering> {
         self.0.partial_cmp(other.as_ref())
     }
 }
 impl<T: PartialOrd> PartialOrd<NonEmptySlice<T>> for [T] {
     #[inline]
-    fn partial_cmp(&self, other: &NonEmptySlice<T>) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &NonEmptySlice<T>) -> Option<core::cmp::Ordering> {
         self.partial_cmp(&other.0)
     }
 }
@@ -853,7 +2595,7 @@ impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
 /// Proper use.
 /// ```
 /// # use non_empty_vec::*;
-/// # use std::convert::TryFrom;
+/// # use core::convert::TryFrom;
 /// assert_eq!(
 ///     ne_vec![1, 2, 3],
 ///     NonEmpty::try_from(vec![1, 2, 3_i32]).unwrap(),
@@ -885,14 +2627,16 @@ impl<'a, T> IntoIterator for &'a mut NonEmptySlice<T> {
 /// let n = 0;
 /// let _ = ne_vec![1; n];
 /// ```
+#[cfg(feature = "alloc")]
 #[macro_export]
 macro_rules! ne_vec {
     () => {
-        ::std::compile_error!("`NonEmpty` vector must be non-empty")
-    };
-    ($($x:expr),+ $(,)?) => {
-        unsafe { $crate::NonEmpty::new_unchecked(vec![$($x),+]) }
+        ::core::compile_error!("`NonEmpty` vector must be non-empty")
     };
+    ($($x:expr),+ $(,)?) => {{
+        let vec = $crate::__private::vec![$($x),+];
+        unsafe { $crate::NonEmpty::new_unchecked(vec) }
+    }};
     ($elem:expr; 0) => {
         // if 0 is passed to the macro we can generate a good compile error
         ne_vec![]
@@ -900,18 +2644,93 @@ macro_rules! ne_vec {
     ($elem:expr; $n:literal) => {{
         // extra guard to reject compilation if $n ends up being 0 in some other way (e.g. ne_vec![1; 0usize])
         const _ASSERT_NON_ZERO: [(); $n - 1] = [(); $n - 1];
-        unsafe { $crate::NonEmpty::new_unchecked(vec![$elem; $n]) }
+        let vec = $crate::__private::vec![$elem; $n];
+        unsafe { $crate::NonEmpty::new_unchecked(vec) }
     }};
     ($elem:expr; $n:expr) => {{
         // if $n is an expression, we cannot check the length at compile time and do it at runtime
         if $n == 0 {
-            ::std::panic!("`NonEmpty` vector must be non-empty");
+            ::core::panic!("`NonEmpty` vector must be non-empty");
         }
-        unsafe { $crate::NonEmpty::new_unchecked(vec![$elem; $n]) }
+        let vec = $crate::__private::vec![$elem; $n];
+        unsafe { $crate::NonEmpty::new_unchecked(vec) }
+    }};
+}
+
+/// Constructs a `&'static` [`NonEmptySlice`] from a list of constant elements.
+///
+/// The length is checked at compile time, so the macro can be used to initialize `const` and `static` items.
+///
+/// # Examples
+/// Proper use.
+/// ```
+/// # use non_empty_vec::{ne_slice, NonEmptySlice};
+/// const PRIMES: &NonEmptySlice<u32> = ne_slice![2, 3, 5, 7];
+/// assert_eq!(PRIMES.first(), &2);
+///
+/// static ZEROS: &NonEmptySlice<u8> = ne_slice![0; 4];
+/// assert_eq!(ZEROS, &[0, 0, 0, 0]);
+/// ```
+/// Improper use.
+/// ```compile_fail
+/// # use non_empty_vec::*;
+/// let _: &NonEmptySlice<i32> = ne_slice![];
+/// ```
+///
+/// ```compile_fail
+/// # use non_empty_vec::*;
+/// let _ = ne_slice![1; 0];
+/// ```
+///
+/// The elements must be constant, as the slice is not allowed to borrow from a temporary.
+/// ```compile_fail
+/// # use non_empty_vec::*;
+/// let x = 5;
+/// let _ = ne_slice![x];
+/// ```
+#[macro_export]
+macro_rules! ne_slice {
+    () => {
+        ::core::compile_error!("`NonEmptySlice` must be non-empty")
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::__private::ne_slice(&[$($x),+])
+    };
+    ($elem:expr; $n:expr) => {{
+        // reject compilation if $n is 0
+        const _ASSERT_NON_ZERO: [(); $n - 1] = [(); $n - 1];
+        $crate::__private::ne_slice(&[$elem; $n])
+    }};
+}
+
+/// Constructs a `&'static` [`NonEmptyStr`] from a string literal or other constant string.
+///
+/// The length is checked at compile time, so the macro can be used to initialize `const` and `static` items.
+///
+/// # Examples
+/// Proper use.
+/// ```
+/// # use non_empty_vec::{ne_str, NonEmptyStr};
+/// const NAME: &NonEmptyStr = ne_str!("non-empty-vec");
+/// assert_eq!(NAME.first_char(), 'n');
+///
+/// let greeting = ne_str!(concat!("hello", ", ", "world"));
+/// assert_eq!(greeting, "hello, world");
+/// ```
+/// Improper use.
+/// ```compile_fail
+/// # use non_empty_vec::*;
+/// let _ = ne_str!("");
+/// ```
+#[macro_export]
+macro_rules! ne_str {
+    ($s:expr $(,)?) => {{
+        const S: &$crate::NonEmptyStr = $crate::__private::ne_str($s);
+        S
     }};
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
@@ -951,6 +2770,476 @@ mod tests {
         assert_eq!(single[0], 15);
     }
 
+    #[test]
+    fn errors() {
+        let err = NonEmpty::try_from(Vec::<i32>::with_capacity(4)).unwrap_err();
+        assert_eq!(err.to_string(), "collection must not be empty");
+        assert!(err.as_inner().capacity() >= 4);
+
+        // Both errors can be used with `?`.
+        fn parse(v: Vec<i32>) -> Result<NonEmpty<i32>, Box<dyn std::error::Error>> {
+            let mut v = NonEmpty::try_from(v)?;
+            v.try_remove(0)?;
+            Ok(v)
+        }
+        assert_eq!(parse(vec![1, 2]).unwrap(), ne_vec![2]);
+        assert!(parse(vec![]).is_err());
+        assert!(parse(vec![1]).is_err());
+
+        let err: EmptyError = NonEmpty::try_from(Box::<[i32]>::from([]))
+            .unwrap_err()
+            .into();
+        assert_eq!(err, EmptyError);
+    }
+
+    #[test]
+    fn string() {
+        use core::num::NonZeroUsize;
+        use std::collections::HashSet;
+
+        let err = NonEmptyString::try_from(String::with_capacity(4)).unwrap_err();
+        assert!(err.into_inner().capacity() >= 4);
+        assert_eq!(NonEmptyString::try_from(""), Err(EmptyError));
+        assert_eq!("".parse::<NonEmptyString>(), Err(EmptyError));
+        assert_eq!(<&NonEmptyStr>::try_from(""), Err(EmptyError));
+
+        let mut s: NonEmptyString = "añb".parse().unwrap();
+        assert_eq!(s.len().get(), 4);
+        assert_eq!((s.first_char(), s.last_char()), ('a', 'b'));
+        s.as_mut_str().make_ascii_uppercase();
+        assert_eq!(s, "AñB");
+        assert_eq!(s.pop(), Some('B'));
+        assert_eq!(s.pop(), Some('ñ'));
+        assert_eq!(s.pop(), None);
+        s.push_str("bc");
+        s.truncate(NonZeroUsize::new(10).unwrap());
+        assert_eq!(s, "Abc");
+        s.truncate(NonZeroUsize::new(1).unwrap());
+        assert_eq!(s.to_string(), "A");
+
+        let boxed = NonEmptyString::from('é').into_boxed_str();
+        assert_eq!(&*boxed, "é");
+        assert_eq!(NonEmptyString::from(boxed), "é");
+
+        let set: HashSet<NonEmptyString> = ["a", "b"].iter().map(|s| s.parse().unwrap()).collect();
+        assert!(set.contains("a"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn string_truncate_char_boundary() {
+        let mut s = NonEmptyString::try_from("ñ").unwrap();
+        s.truncate(core::num::NonZeroUsize::new(1).unwrap());
+    }
+
+    #[test]
+    fn static_macros() {
+        const SLICE: &NonEmptySlice<&str> = ne_slice!["a", "b"];
+        static STR: &NonEmptyStr = ne_str!("abc");
+        assert_eq!(SLICE.last(), &"b");
+        assert_eq!(ne_slice![[1, 2]; 3].len().get(), 3);
+        assert_eq!(STR.last_char(), 'c');
+        let s: &'static NonEmptyStr = ne_str!("x");
+        assert_eq!(NonEmptyString::from(s), "x");
+    }
+
+    #[test]
+    fn arrays() {
+        let mut v = NonEmpty::from([1, 2, 3]);
+        *v.as_mut_array::<3>().unwrap() = [1, 4, 5];
+        *v.first_chunk1_mut() = [0];
+        v.last_chunk1_mut()[0] += 1;
+        assert_eq!(v.as_mut_array::<4>(), None);
+        assert_eq!(<[i32; 3]>::try_from(v), Ok([0, 4, 6]));
+
+        let s: &NonEmptySlice<_> = From::from(&["a"]);
+        assert_eq!(s.first_chunk1(), s.last_chunk1());
+        assert_eq!(s.as_array::<1>(), Some(s.first_chunk1()));
+        assert_eq!(<[&str; 0]>::try_from(ne_vec!["a"]), Err(ne_vec!["a"]));
+    }
+
+    #[test]
+    fn borrow() {
+        use std::borrow::Cow;
+        use std::collections::{BTreeSet, HashMap};
+
+        let mut map = HashMap::new();
+        map.insert(ne_vec![1, 2], "a");
+        assert_eq!(map.get(&[1, 2][..]), Some(&"a"));
+        assert_eq!(map.get(NonEmptySlice::from_ref(&1)), None);
+
+        let set: BTreeSet<_> = vec![ne_vec![3], ne_vec![1, 2]].into_iter().collect();
+        assert!(set.contains(&[3][..]));
+
+        let array = [String::from("x")];
+        let s: &NonEmptySlice<_> = From::from(&array);
+        let cow = Cow::Borrowed(s);
+        assert_eq!(cow.len().get(), 1);
+        let boxed = cow.into_owned().into_boxed_slice();
+        assert_eq!(boxed.clone(), boxed);
+
+        let mut strings = HashMap::new();
+        strings.insert(NonEmptyString::new('a'), 1);
+        assert_eq!(strings.get(ne_str!("a")), Some(&1));
+        let cow: Cow<NonEmptyStr> = Cow::Borrowed(ne_str!("b"));
+        assert_eq!(cow.into_owned().into_boxed_str().clone().first_char(), 'b');
+    }
+
+    #[test]
+    fn shared() {
+        use std::rc::Rc;
+        use std::sync::{Arc, Weak};
+        use std::thread;
+
+        let arc: Arc<NonEmptySlice<i32>> = Arc::from(ne_vec![1, 2, 3]);
+        let handle = {
+            let arc = Arc::clone(&arc);
+            thread::spawn(move || *arc.last())
+        };
+        assert_eq!(handle.join().unwrap(), 3);
+
+        let mut unique = Arc::from(&*arc);
+        let ptr = Arc::as_ptr(&unique);
+        NonEmptySlice::make_mut_arc(&mut unique)[1] = 4;
+        assert_eq!(Arc::as_ptr(&unique), ptr);
+        assert_eq!(*unique, [1, 4, 3]);
+
+        // Weak references also force a clone.
+        let weak: Weak<NonEmptySlice<i32>> = Arc::downgrade(&unique);
+        NonEmptySlice::make_mut_arc(&mut unique)[0] = 0;
+        assert!(weak.upgrade().is_none());
+        assert_eq!(*unique, [0, 4, 3]);
+
+        let err = NonEmptySlice::<i32>::from_arc_slice(Arc::from(vec![])).unwrap_err();
+        assert!(err.into_inner().is_empty());
+        let arc = NonEmptySlice::from_arc_slice(unique.into_arc_slice()).unwrap();
+        assert_eq!(*arc, [0, 4, 3]);
+
+        let rc: Rc<NonEmptySlice<_>> = Rc::from(ne_vec![String::from("a")].into_boxed_slice());
+        let rc2 = NonEmptySlice::from_rc_slice(Rc::clone(&rc).into_rc_slice()).unwrap();
+        assert!(Rc::ptr_eq(&rc, &rc2));
+    }
+
+    #[test]
+    fn vec_deque() {
+        use std::collections::VecDeque;
+
+        let mut d = NonEmptyVecDeque::new(1);
+        assert_eq!(d.pop_back(), None);
+        d.push_back(2);
+        d.push_front(0);
+        d.rotate_right(1);
+        assert_eq!((d.front(), d.back(), d.len().get()), (&2, &1, 3));
+        *d.back_mut() = 5;
+        d[0] = 4;
+        assert_eq!(d.make_contiguous(), &[4, 0, 5]);
+        assert_eq!(NonEmpty::from(d.clone()), ne_vec![4, 0, 5]);
+
+        assert_eq!(d.pop_back(), Some(5));
+        assert_eq!(d.pop_front(), Some(4));
+        assert_eq!(d.pop_front(), None);
+        assert_eq!(d.pop_back(), None);
+        assert_eq!(d.front(), &0);
+
+        let err = NonEmptyVecDeque::try_from(VecDeque::<i32>::new()).unwrap_err();
+        assert!(err.into_inner().is_empty());
+        let d: NonEmptyVecDeque<_> = ne_vec![1, 2].into_ne_iter().rev().collect();
+        assert_eq!(d.iter().collect::<Vec<_>>(), [&2, &1]);
+    }
+
+    #[test]
+    fn btree() {
+        use std::collections::{BTreeMap, BTreeSet};
+
+        let mut map = NonEmptyBTreeMap::from(ne_vec![(2, "b"), (1, "a"), (2, "c")]);
+        assert_eq!(map.len().get(), 2);
+        assert_eq!(map[&2], "c");
+        assert_eq!(map.pop_last(), Some((2, "c")));
+        assert_eq!(map.pop_first(), None);
+        assert_eq!(map.pop_last(), None);
+        assert_eq!(map.try_remove(&1), Err(EmptyError));
+        map.extend(vec![(3, "d"), (4, "e")]);
+        for v in map.values_mut() {
+            *v = "x";
+        }
+        assert_eq!(map.remove(&1), Some("x"));
+        let (first, rest) = map.keys().next();
+        assert_eq!((first, rest.count()), (&3, 1));
+        let pairs: NonEmpty<_> = map.clone().into_ne_iter().collect();
+        assert_eq!(pairs, ne_vec![(3, "x"), (4, "x")]);
+        assert_eq!(map.ne_iter().first(), map.first_key_value());
+        let err = NonEmptyBTreeMap::try_from(BTreeMap::<i32, i32>::new()).unwrap_err();
+        assert!(err.into_inner().is_empty());
+
+        let mut set: NonEmptyBTreeSet<_> = ne_vec![5, 3, 5].into_ne_iter().collect();
+        assert_eq!((set.first(), set.last()), (&3, &5));
+        assert!(set.insert(4));
+        assert_eq!(set.pop_first(), Some(3));
+        assert_eq!(set.try_remove(&4), Ok(true));
+        assert_eq!(set.try_remove(&5), Err(EmptyError));
+        assert!(!set.remove(&5));
+        assert_eq!(set.pop_last(), None);
+        assert_eq!(BTreeSet::from(set.clone()), [5].iter().copied().collect());
+        assert_eq!(set.ne_iter().first(), &5);
+    }
+
+    /// A key whose comparisons alternate between unequal and equal, sharing a call counter.
+    struct Flaky<'a>(&'a std::cell::Cell<usize>);
+    impl Flaky<'_> {
+        fn flip(&self) -> bool {
+            let n = self.0.get();
+            self.0.set(n + 1);
+            n % 2 == 1
+        }
+    }
+    impl PartialEq for Flaky<'_> {
+        fn eq(&self, _: &Self) -> bool {
+            self.flip()
+        }
+    }
+    impl Eq for Flaky<'_> {}
+    impl PartialOrd for Flaky<'_> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Flaky<'_> {
+        fn cmp(&self, _: &Self) -> Ordering {
+            if self.flip() {
+                Ordering::Equal
+            } else {
+                Ordering::Less
+            }
+        }
+    }
+    impl std::hash::Hash for Flaky<'_> {
+        fn hash<H: std::hash::Hasher>(&self, _: &mut H) {}
+    }
+
+    #[test]
+    fn btree_inconsistent_ord() {
+        let calls = std::cell::Cell::new(0);
+        let mut map = NonEmptyBTreeMap::new(Flaky(&calls), 10);
+        let mut set = NonEmptyBTreeSet::new(Flaky(&calls));
+        for _ in 0..4 {
+            let _ = map.try_remove(&Flaky(&calls));
+            let _ = map.remove(&Flaky(&calls));
+            let _ = set.try_remove(&Flaky(&calls));
+            let _ = set.remove(&Flaky(&calls));
+        }
+        assert_eq!(map.as_btree_map().len(), 1);
+        assert_eq!(set.as_btree_set().len(), 1);
+    }
+
+    #[test]
+    fn hash_inconsistent_eq() {
+        let calls = std::cell::Cell::new(0);
+        let mut map: NonEmptyHashMap<_, _> = NonEmptyHashMap::new(Flaky(&calls), 10);
+        let mut set: NonEmptyHashSet<_> = NonEmptyHashSet::new(Flaky(&calls));
+        for _ in 0..4 {
+            let _ = map.try_remove(&Flaky(&calls));
+            let _ = map.remove(&Flaky(&calls));
+            let _ = set.try_remove(&Flaky(&calls));
+            let _ = set.remove(&Flaky(&calls));
+        }
+        assert_eq!(map.as_hash_map().len(), 1);
+        assert_eq!(set.as_hash_set().len(), 1);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::{HashMap, HashSet};
+        use std::hash::BuildHasherDefault;
+
+        type Map<K, V> = NonEmptyHashMap<K, V, BuildHasherDefault<DefaultHasher>>;
+        let mut map: Map<_, _> = NonEmptyHashMap::from(ne_vec![("a", 1), ("b", 2), ("a", 3)]);
+        assert_eq!(map.len().get(), 2);
+        assert_eq!(map["a"], 3);
+        assert_eq!(map.try_remove("a"), Ok(Some(3)));
+        assert_eq!(map.try_remove("b"), Err(EmptyError));
+        assert_eq!(map.remove("b"), None);
+        assert_eq!(map.any_entry(), (&"b", &2));
+        map.extend(vec![("c", 4)]);
+        for v in map.values_mut() {
+            *v *= 10;
+        }
+        let values: NonEmptyBTreeSet<_> = map.values().copied().collect();
+        assert_eq!(values, NonEmptyBTreeSet::from(ne_vec![20, 40]));
+        assert_eq!(map.keys().into_iter().count(), 2);
+        let err = NonEmptyHashMap::try_from(HashMap::<i32, i32>::new()).unwrap_err();
+        assert!(err.into_inner().is_empty());
+
+        let mut set = NonEmptyHashSet::new(1);
+        assert!(set.insert(2));
+        assert!(set.remove(&1));
+        assert_eq!(set.try_remove(&2), Err(EmptyError));
+        assert_eq!(set.any_element(), &2);
+        assert_eq!(set, NonEmptyHashSet::from(ne_vec![2, 2]));
+        let doubled: NonEmptyHashSet<_> = set.into_ne_iter().map(|x| x * 2).collect();
+        assert_eq!(HashSet::from(doubled), [4].iter().copied().collect());
+    }
+
+    #[test]
+    fn binary_heap() {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+
+        let mut heap: NonEmptyBinaryHeap<_> =
+            ne_vec![3, 1, 4].into_ne_iter().map(Reverse).collect();
+        assert_eq!(heap.peek(), &Reverse(1));
+        heap.peek_mut().0 = 5;
+        assert_eq!(heap.peek(), &Reverse(3));
+        heap.push(Reverse(0));
+        assert_eq!(heap.pop(), Some(Reverse(0)));
+        assert_eq!(heap.pop(), Some(Reverse(3)));
+        assert_eq!(heap.pop(), Some(Reverse(4)));
+        assert_eq!(heap.pop(), None);
+        assert_eq!(heap.len().get(), 1);
+        assert_eq!(NonEmpty::from(heap), ne_vec![Reverse(5)]);
+
+        let err = NonEmptyBinaryHeap::try_from(BinaryHeap::<i32>::new()).unwrap_err();
+        assert!(err.into_inner().is_empty());
+        let heap = NonEmptyBinaryHeap::from(ne_vec![2, 3, 1]);
+        assert_eq!(heap.clone().into_sorted_vec(), ne_vec![1, 2, 3]);
+        assert_eq!(BinaryHeap::from(heap).into_sorted_vec(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn chunks() {
+        let size = |n| NonZeroUsize::new(n).unwrap();
+        let mut v = ne_vec![1, 2, 3, 4, 5];
+
+        let chunks: NonEmpty<_> = v.chunks_nz(size(2)).map(|c| c.len().get()).collect();
+        assert_eq!(chunks, ne_vec![2, 2, 1]);
+        let chunks: NonEmpty<_> = v.rchunks_nz(size(2)).map(|c| *c.first()).collect();
+        assert_eq!(chunks, ne_vec![4, 2, 1]);
+        assert_eq!(v.chunks_nz(size(10)).first(), &v);
+        assert_eq!(v.rchunks_nz(size(10)).first(), &v);
+
+        for chunk in v.chunks_mut_nz(size(3)) {
+            *chunk.first_mut() = 0;
+        }
+        for chunk in v.rchunks_mut_nz(size(4)) {
+            *chunk.last_mut() += 10;
+        }
+        assert_eq!(v, ne_vec![10, 2, 3, 0, 15]);
+
+        assert_eq!(v.chunks_exact_nz(size(2)).count(), 2);
+        assert_eq!(v.chunks_exact_nz(size(6)).count(), 0);
+        for chunk in v.chunks_exact_mut_nz(size(5)) {
+            chunk.reverse();
+        }
+        assert_eq!(v, ne_vec![15, 0, 3, 2, 10]);
+        assert_eq!(
+            v.windows_nz(size(4)).map(|w| *w.last()).collect::<Vec<_>>(),
+            [2, 10]
+        );
+        assert_eq!(v.windows_nz(size(6)).count(), 0);
+    }
+
+    #[test]
+    fn group() {
+        let v = ne_vec![1, 2, 4, 3, 5, 6];
+        let (first, rest) = v.chunk_by(|a, b| a < b).next();
+        assert_eq!(first, &[1, 2, 4]);
+        assert_eq!(rest.map(|s| s.as_slice()).collect::<Vec<_>>(), [&[3, 5, 6]]);
+        assert_eq!(ne_vec![1].chunk_by(|_, _| false).first(), &[1]);
+        // Cloning doesn't need `T: Clone`.
+        struct NoClone(i32);
+        let items = ne_vec![NoClone(1), NoClone(1), NoClone(2)];
+        let runs = items.chunk_by(|a, b| a.0 == b.0);
+        assert_eq!(runs.clone().into_iter().count(), 2);
+        assert_eq!(runs.first().len().get(), 2);
+
+        let groups = v.clone().group_by_key(|x| x % 2);
+        let expected = ne_vec![
+            (1, ne_vec![1]),
+            (0, ne_vec![2, 4]),
+            (1, ne_vec![3, 5]),
+            (0, ne_vec![6])
+        ];
+        assert_eq!(groups, expected);
+        assert_eq!(ne_vec![7].group_by_key(|_| ()), ne_vec![((), ne_vec![7])]);
+    }
+
+    #[test]
+    fn group_map() {
+        let pairs = vec![(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (1, 'e')];
+        let map = pairs.clone().into_group_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&1], ne_vec!['a', 'c', 'e']);
+        assert_eq!(map[&3], ne_vec!['d']);
+
+        let hasher =
+            std::hash::BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default();
+        let map = pairs.iter().copied().into_group_map_with_hasher(hasher);
+        assert_eq!(map[&1], ne_vec!['a', 'c', 'e']);
+
+        let map = pairs.into_group_btree_map();
+        let sizes: Vec<_> = map.values().map(|v| v.len().get()).collect();
+        assert_eq!(sizes, [3, 1, 1]);
+        assert!(std::iter::empty::<((), ())>().into_group_map().is_empty());
+    }
+
+    #[test]
+    fn extensions() {
+        use std::collections::{BTreeMap, HashSet, VecDeque};
+
+        let mut v: Vec<_> = (1..=3).rev().collect();
+        v.as_non_empty_mut().unwrap().sort();
+        assert_eq!(v.as_non_empty().unwrap().first(), &1);
+        assert!(Vec::<i32>::new().as_non_empty().is_none());
+        let mut s = String::from("ab");
+        s.as_mut_str()
+            .as_non_empty_mut()
+            .unwrap()
+            .make_ascii_uppercase();
+        assert_eq!(s.into_non_empty().unwrap(), "AB");
+        assert!(String::new().into_non_empty().is_err());
+
+        assert_eq!(
+            VecDeque::from(vec![1]).into_non_empty().unwrap().front(),
+            &1
+        );
+        assert!(BTreeMap::<i32, i32>::new().into_non_empty().is_err());
+        let set: HashSet<_> = [1].iter().copied().collect();
+        assert_eq!(set.into_non_empty().unwrap().any_element(), &1);
+
+        let evens = (1..10).filter(|x| x % 2 == 0).collect_non_empty();
+        assert_eq!(evens, Some(ne_vec![2, 4, 6, 8]));
+        assert_eq!((1..10).filter(|x| *x > 10).collect_non_empty(), None);
+    }
+
+    #[test]
+    fn validated() {
+        let ok: Validated<i32, &str> = Validated::Ok(1);
+        let a: Validated<i32, &str> = Validated::Err(ne_vec!["a"]);
+        let bc: Validated<i32, &str> = Validated::Err(ne_vec!["b", "c"]);
+        assert!(ok.is_ok() && a.is_err());
+
+        assert_eq!(ok.clone().zip(ok.clone()), Validated::Ok((1, 1)));
+        assert_eq!(a.clone().zip(ok.clone()), Validated::Err(ne_vec!["a"]));
+        assert_eq!(
+            bc.clone().and(a.clone()),
+            Validated::Err(ne_vec!["b", "c", "a"])
+        );
+        assert_eq!(ok.clone().map(|x| x + 1).ok(), Some(2));
+        assert_eq!(bc.clone().map_err(str::len).err(), Some(ne_vec![1, 1]));
+
+        assert_eq!(Result::from(ok), Ok(1));
+        assert_eq!(Validated::from(Result::<i32, _>::Err(ne_vec!["a"])), a);
+        assert_eq!(bc.into_result(), Err(ne_vec!["b", "c"]));
+
+        let v: Validated<Vec<i32>, _> =
+            vec![Ok(1), Err("x"), Ok(2), Err("y")].into_iter().collect();
+        assert_eq!(v, Validated::Err(ne_vec!["x", "y"]));
+        let v: Validated<Vec<i32>, &str> = vec![Ok(1), Ok(2)].into_iter().collect();
+        assert_eq!(v, Validated::Ok(vec![1, 2]));
+        let v: Validated<Vec<i32>, &str> = core::iter::empty().collect();
+        assert_eq!(v, Validated::Ok(vec![]));
+    }
+
     #[test]
     fn default() {
         assert_eq!(NonEmpty::<i32>::default(), ne_vec![0]);
@@ -975,27 +3264,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ne_iter() {
+        let mut v = ne_vec![1, 2, 3];
+        for x in v.ne_iter_mut() {
+            *x *= 10;
+        }
+
+        let mut seen = vec![];
+        let (first, rest) = v
+            .ne_iter()
+            .cloned()
+            .rev()
+            .inspect(|x| seen.push(*x))
+            .chain(vec![0])
+            .next();
+        assert_eq!(first, 30);
+        assert!(rest.eq([20, 10, 0]));
+        assert_eq!(seen, [30, 20, 10]);
+
+        let flat: NonEmpty<_> = v
+            .ne_iter()
+            .enumerate()
+            .flat_map(|(i, x)| {
+                NonEmpty::new(i)
+                    .into_ne_iter()
+                    .zip(ne_vec![*x, 0].into_ne_iter())
+            })
+            .collect();
+        assert_eq!(flat, ne_vec![(0, 10), (1, 20), (2, 30)]);
+    }
+
     #[test]
     fn drain_filter() {
         // Filter out odd numbers.
         let mut v = ne_vec![1, 2, 3, 4, 5, 6];
-        assert!(v.drain_filter(|val| *val % 2 == 1).eq([1, 3, 5]));
+        assert!(v
+            .drain_filter(Survivor::MostRecent, |val| *val % 2 == 1)
+            .eq([1, 3, 5]));
         assert_eq!(v, ne_vec![2, 4, 6]);
 
         // singleton
         let mut v = ne_vec![1];
-        for _ in v.drain_filter(|_| unreachable!()) {}
-        assert_eq!(v, ne_vec![1]);
+        let mut calls = 0;
+        for _ in v.drain_filter(Survivor::MostRecent, |_| {
+            calls += 1;
+            true
+        }) {}
+        assert_eq!((v, calls), (ne_vec![1], 1));
 
         // leftover
         let mut v = ne_vec![1, 2, 3];
-        let removed = v.drain_filter(|&mut val| if val < 3 { true } else { unreachable!() });
+        let removed = v.drain_filter(Survivor::MostRecent, |_| true);
         assert!(removed.eq([1, 2]));
         assert_eq!(v, ne_vec![3]);
 
         // double-ended, meet in middle
         let mut v = ne_vec![1, 2, 3, 4, 5, 6];
-        let mut rem = v.drain_filter(|val| *val % 2 == 1);
+        let mut rem = v.drain_filter(Survivor::MostRecent, |val| *val % 2 == 1);
         assert_eq!(rem.next(), Some(1));
         assert_eq!(rem.next_back(), Some(5));
         assert_eq!(rem.next_back(), Some(3));
@@ -1004,31 +3330,309 @@ mod tests {
 
         // rev
         let mut v = ne_vec![1, 2, 3, 4, 5, 6];
-        let rem = v.drain_filter(|val| *val % 2 == 0).rev();
+        let rem = v
+            .drain_filter(Survivor::MostRecent, |val| *val % 2 == 0)
+            .rev();
         assert!(rem.eq([6, 4, 2]));
         assert_eq!(v, ne_vec![1, 3, 5]);
 
         // singleton-back
         let mut v = ne_vec![1];
-        for _ in v.drain_filter(|_| unreachable!()) {}
+        for _ in v.drain_filter(Survivor::MostRecent, |_| true).rev() {}
         assert_eq!(v, ne_vec![1]);
 
         // leftover-back
         let mut v = ne_vec![1, 2, 3];
-        let removed = v
-            .drain_filter(|&mut val| if val > 1 { true } else { unreachable!() })
-            .rev();
+        let removed = v.drain_filter(Survivor::MostRecent, |_| true).rev();
         assert!(removed.eq([3, 2]));
         assert_eq!(v, ne_vec![1]);
 
-        // meet in middle, unreachable
+        // meet in middle, every element matches
         let mut v = ne_vec![1, 2, 3];
-        let mut rem = v.drain_filter(|&mut val| if val == 2 { unreachable!() } else { true });
+        let mut rem = v.drain_filter(Survivor::MostRecent, |_| true);
         assert_eq!(rem.next_back(), Some(3));
         assert_eq!(rem.next(), Some(1));
         assert_eq!(rem.next_back(), None);
         assert_eq!(rem.next(), None);
+        drop(rem);
         assert_eq!(v, ne_vec![2]);
+
+        // dropped part way through
+        let mut v = ne_vec![1, 2, 3, 4, 5, 6];
+        let mut rem = v.drain_filter(Survivor::MostRecent, |val| *val % 3 != 0);
+        assert_eq!(rem.next(), Some(1));
+        assert_eq!(rem.next_back(), Some(5));
+        drop(rem);
+        assert_eq!(v, ne_vec![2, 3, 4, 6]);
+
+        // large input
+        let mut v: NonEmpty<_> = (0, (1..100_000).collect()).into();
+        assert!(v
+            .drain_filter(Survivor::MostRecent, |val| *val % 2 == 0)
+            .eq((0..100_000).step_by(2)));
+        assert!(v.iter().copied().eq((1..100_000).step_by(2)));
+    }
+
+    #[test]
+    fn drain_filter_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut v =
+            NonEmpty::try_from((0..8).map(|i| (i, Rc::clone(&counter))).collect::<Vec<_>>())
+                .unwrap();
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut rem = v.drain_filter(Survivor::MostRecent, |(i, _)| {
+                if *i == 4 {
+                    panic!()
+                } else {
+                    *i % 2 == 0
+                }
+            });
+            assert_eq!(rem.next().map(|(i, _)| i), Some(0));
+            assert_eq!(rem.next_back().map(|(i, _)| i), Some(6));
+            rem.for_each(drop);
+        }));
+        assert!(result.is_err());
+        // Elements that were not examined yet are left alone.
+        assert!(v.iter().map(|(i, _)| *i).eq([1, 3, 4, 5, 7]));
+        // Nothing was leaked or dropped twice.
+        assert_eq!(Rc::strong_count(&counter), 6);
+    }
+
+    #[test]
+    fn drain_filter_survivor() {
+        use std::collections::VecDeque;
+
+        // Simple LCG, so that the test is deterministic.
+        let mut seed = 0x2545_f491_u64;
+        let mut rand = move |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % n
+        };
+        let policies = [
+            Survivor::First,
+            Survivor::Last,
+            Survivor::MostRecent,
+            Survivor::Fail,
+        ];
+        for _ in 0..2000 {
+            let len = rand(8) as usize + 1;
+            // Bias towards every element matching.
+            let matches: Vec<bool> = if rand(2) == 0 {
+                vec![true; len]
+            } else {
+                (0..len).map(|_| rand(3) != 0).collect()
+            };
+            // Sometimes stop early, leaving elements unexamined.
+            let steps = if rand(2) == 0 {
+                len + 1
+            } else {
+                rand(len as u64 + 1) as usize + 1
+            };
+            let directions: Vec<bool> = (0..steps).map(|_| rand(2) == 0).collect();
+            let survivor = policies[rand(4) as usize];
+
+            let mut expected: VecDeque<usize> = (0..len).filter(|&i| matches[i]).collect();
+            if expected.len() == len {
+                match survivor {
+                    Survivor::First => {
+                        expected.pop_front();
+                    }
+                    Survivor::Last => {
+                        expected.pop_back();
+                    }
+                    Survivor::Fail => expected.clear(),
+                    // Decided lazily, see below.
+                    Survivor::MostRecent => {}
+                }
+            }
+            // `Survivor::MostRecent` only examines elements as they are visited, so follow along.
+            let mut unexamined: VecDeque<usize> = (0..len).collect();
+            let mut retained = false;
+            let mut v = NonEmpty::try_from((0..len).collect::<Vec<_>>()).unwrap();
+            let mut calls = vec![0; len];
+            let mut iter = v.drain_filter(survivor, |&mut i| {
+                calls[i] += 1;
+                matches[i]
+            });
+            let mut yielded = vec![];
+            for &back in &directions {
+                let (actual, expected) = if survivor == Survivor::MostRecent {
+                    let expected = loop {
+                        let next = if back {
+                            unexamined.pop_back()
+                        } else {
+                            unexamined.pop_front()
+                        };
+                        match next {
+                            Some(i) if matches[i] && (retained || !unexamined.is_empty()) => {
+                                break Some(i)
+                            }
+                            Some(_) => retained = true,
+                            None => break None,
+                        }
+                    };
+                    let actual = if back { iter.next_back() } else { iter.next() };
+                    (actual, expected)
+                } else if back {
+                    (iter.next_back(), expected.pop_back())
+                } else {
+                    (iter.next(), expected.pop_front())
+                };
+                assert_eq!(actual, expected);
+                yielded.extend(actual);
+            }
+            let failed = survivor == Survivor::Fail && matches.iter().all(|&m| m);
+            assert_eq!(iter.result().is_err(), failed);
+            drop(iter);
+            assert!(v
+                .iter()
+                .copied()
+                .eq((0..len).filter(|i| !yielded.contains(i))));
+            assert!(calls.iter().all(|&c| c <= 1));
+            if survivor == Survivor::MostRecent {
+                assert!((0..len).all(|i| (calls[i] == 1) != unexamined.contains(&i)));
+            }
+            if steps > len {
+                assert!(calls.iter().all(|&c| c == 1));
+            }
+        }
+    }
+
+    #[test]
+    fn aggregates() {
+        let v = ne_vec![(3, 'a'), (1, 'b'), (3, 'c'), (1, 'd')];
+        let s: &NonEmptySlice<_> = &v;
+        assert_eq!(s.max(), &(3, 'c'));
+        assert_eq!(s.min(), &(1, 'b'));
+        assert_eq!(v.max_by_key(|p| p.0), &(3, 'c'));
+        assert_eq!(v.min_by_key(|p| p.0), &(1, 'b'));
+        assert_eq!(v.max_by(|a, b| a.1.cmp(&b.1)), &(1, 'd'));
+        assert_eq!(v.min_by(|a, b| a.1.cmp(&b.1)), &(3, 'a'));
+        assert_eq!(v.minmax(), (&(1, 'b'), &(3, 'c')));
+        assert_eq!((v.position_min(), v.position_max()), (1, 2));
+        assert_eq!(v.reduce(|a, b| if b.1 > a.1 { b } else { a }), &(1, 'd'));
+        assert_eq!(v.fold1(|x| x.0, |acc, x| acc + x.0), 8);
+
+        // Ties are broken the same way as on slices.
+        #[derive(Debug)]
+        struct ByKey(i32, char);
+        impl PartialEq for ByKey {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for ByKey {}
+        impl PartialOrd for ByKey {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for ByKey {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        let keyed: &NonEmptySlice<_> = &ne_vec![ByKey(1, 'a'), ByKey(2, 'b'), ByKey(2, 'c')];
+        assert_eq!(keyed.max().1, 'c');
+        let keyed: &NonEmptySlice<_> = &ne_vec![ByKey(2, 'a'), ByKey(1, 'b'), ByKey(1, 'c')];
+        assert_eq!(keyed.min().1, 'b');
+
+        let one = ne_vec![7];
+        assert_eq!(one.minmax(), (&7, &7));
+        // `Ord::max` on the vectors themselves still resolves.
+        assert_eq!(ne_vec![1].max(ne_vec![2]), ne_vec![2]);
+    }
+
+    #[test]
+    fn try_map() {
+        let v = ne_vec![1_i32, 2, 3];
+        assert_eq!(v.clone().try_map(u8::try_from), Ok(ne_vec![1, 2, 3]));
+        assert!(v.clone().try_map(|i| u8::try_from(i - 2)).is_err());
+
+        let mut calls = 0;
+        let res = v.clone().try_map_opt(|i| {
+            calls += 1;
+            if i == 2 {
+                None
+            } else {
+                Some(i * 10)
+            }
+        });
+        assert_eq!((res, calls), (None, 2));
+        assert_eq!(
+            v.try_map_opt(|i| i.checked_mul(10)),
+            Some(ne_vec![10, 20, 30])
+        );
+    }
+
+    #[test]
+    fn map_in_place() {
+        let v = ne_vec![1_u32, 2, 3];
+        let ptr = v.as_ptr() as usize;
+        let v = v.map_in_place(|i| -(i as i32));
+        assert_eq!(v, ne_vec![-1, -2, -3]);
+        assert_eq!(v.as_ptr() as usize, ptr);
+
+        // Mismatched layouts fall back to `map`.
+        assert_eq!(v.map_in_place(i64::from), ne_vec![-1_i64, -2, -3]);
+        assert_eq!(ne_vec![(); 3].map_in_place(|()| 1_u8), ne_vec![1, 1, 1]);
+        assert_eq!(ne_vec![1_u16, 2].map_in_place(|_| ()), ne_vec![(), ()]);
+    }
+
+    #[test]
+    fn map_in_place_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let v = ne_vec![Rc::clone(&counter); 5];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut i = 0;
+            v.map_in_place(|rc| {
+                i += 1;
+                if i == 3 {
+                    panic!();
+                }
+                Some(rc)
+            })
+        }));
+        assert!(result.is_err());
+        // Every element was dropped exactly once.
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn retain() {
+        // Every element is visited exactly once, in order.
+        let mut v = ne_vec![1, 2, 3, 4, 5];
+        let mut visited = vec![];
+        let res = v.retain(|&i| {
+            visited.push(i);
+            i == 3
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(visited, [1, 2, 3, 4, 5]);
+        assert_eq!(v, ne_vec![3]);
+
+        // The final element is still visited when everything else gets removed.
+        let mut v = ne_vec![1, 2, 3];
+        let mut visited = vec![];
+        let res = v.retain(|&i| {
+            visited.push(i);
+            i == 3
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(visited, [1, 2, 3]);
+        assert_eq!(v, ne_vec![3]);
+
+        let mut v = ne_vec![1];
+        assert_eq!(v.retain(|_| false), Err(EmptyError));
+        assert_eq!(v, ne_vec![1]);
     }
 
     #[test]
@@ -1055,5 +3659,41 @@ mod tests {
             serde_json::from_str::<NonEmpty<u32>>(&serde_json::to_string(&vec).unwrap()).unwrap(),
             vec
         );
+
+        let s = NonEmptyString::new('a');
+        assert_eq!(serde_json::to_string(&s).unwrap(), r#""a""#);
+        assert_eq!(serde_json::from_str::<NonEmptyString>(r#""a""#).unwrap(), s);
+        assert!(serde_json::from_str::<NonEmptyString>(r#""""#).is_err());
+
+        let d = NonEmptyVecDeque::from(ne_vec![1, 2]);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyVecDeque<u32>>(&serde_json::to_string(&d).unwrap())
+                .unwrap(),
+            d
+        );
+        assert!(serde_json::from_str::<NonEmptyVecDeque<u32>>("[]").is_err());
+
+        let map = NonEmptyBTreeMap::new(String::from("a"), 1);
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"a":1}"#);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyBTreeMap<String, u32>>(r#"{"a":1}"#).unwrap(),
+            map
+        );
+        assert!(serde_json::from_str::<NonEmptyBTreeMap<String, u32>>("{}").is_err());
+        assert!(serde_json::from_str::<NonEmptyBTreeSet<u32>>("[]").is_err());
+
+        let map = NonEmptyHashMap::new(String::from("a"), 1);
+        assert_eq!(serde_json::to_string(&map).unwrap(), r#"{"a":1}"#);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyHashMap<String, u32>>(r#"{"a":1}"#).unwrap(),
+            map
+        );
+        assert!(serde_json::from_str::<NonEmptyHashMap<String, u32>>("{}").is_err());
+        let set = NonEmptyHashSet::new(1);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyHashSet<u32>>("[1]").unwrap(),
+            set
+        );
+        assert!(serde_json::from_str::<NonEmptyHashSet<u32>>("[]").is_err());
     }
 }
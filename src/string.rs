@@ -0,0 +1,636 @@
+//! Strings that are guaranteed to be non-empty.
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, boxed::Box, string::String};
+#[cfg(feature = "alloc")]
+use core::borrow::{Borrow, BorrowMut};
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::NonZeroUsize;
+use core::ops;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use crate::EmptyContainerError;
+use crate::EmptyError;
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+use serde::{de::Error, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+/// Non empty string, ensure non empty by construction.
+/// Inherits `str`'s methods through `Deref` to [`NonEmptyStr`].
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `first_char` and `last_char` don't return `Option`.
+/// * `pop` returns `None` if there is only one character in it.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyString(String);
+
+#[cfg(feature = "alloc")]
+impl NonEmptyString {
+    /// Creates a string containing a single character.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyString;
+    /// let s = NonEmptyString::new('a');
+    /// assert_eq!(s, "a");
+    /// ```
+    #[inline]
+    pub fn new(ch: char) -> Self {
+        let mut s = String::with_capacity(ch.len_utf8());
+        s.push(ch);
+        Self(s)
+    }
+
+    /// Constructs a non-empty string without checking its length.
+    ///
+    /// # Safety
+    /// `string` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(string: String) -> Self {
+        debug_assert!(!string.is_empty());
+        Self(string)
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    pub fn as_ne_str(&self) -> &NonEmptyStr {
+        // SAFETY: This string is non-empty.
+        unsafe { NonEmptyStr::unchecked(&self.0) }
+    }
+
+    #[inline]
+    pub fn as_mut_ne_str(&mut self) -> &mut NonEmptyStr {
+        // SAFETY: This string is non-empty.
+        unsafe { NonEmptyStr::unchecked_mut(&mut self.0) }
+    }
+
+    /// Converts this string into a [boxed non-empty string slice](NonEmptyStr).
+    #[inline]
+    pub fn into_boxed_str(self) -> Box<NonEmptyStr> {
+        // SAFETY: This string is non-empty.
+        unsafe { NonEmptyStr::unchecked_boxed(self.0.into_boxed_str()) }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+
+    #[inline]
+    pub fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        self.0.push(ch)
+    }
+
+    /// Appends a string slice onto the end of this string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyString;
+    /// let mut s = NonEmptyString::new('a');
+    /// s.push_str("bc");
+    /// s.push_str("");
+    /// assert_eq!(s, "abc");
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.0.push_str(string)
+    }
+
+    /// Removes the last character from this string and returns it.
+    /// Returns `None` if there is only one character in the string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyString;
+    /// # use core::convert::TryFrom;
+    /// let mut s = NonEmptyString::try_from("ab").unwrap();
+    /// assert_eq!(s.pop(), Some('b'));
+    /// assert_eq!(s.pop(), None);
+    /// assert_eq!(s, "a");
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let (_, rest) = self.split_first_char();
+        if rest.is_empty() {
+            None
+        } else {
+            self.0.pop()
+        }
+    }
+
+    /// Shortens this string to the specified length in bytes.
+    ///
+    /// If `new_len` is greater than the string's current length, this has no effect.
+    /// # Panics
+    /// If `new_len` does not lie on a [`char`] boundary.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyString;
+    /// # use core::convert::TryFrom;
+    /// # use core::num::NonZeroUsize;
+    /// let mut s = NonEmptyString::try_from("hello").unwrap();
+    /// s.truncate(NonZeroUsize::new(2).unwrap());
+    /// assert_eq!(s, "he");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn truncate(&mut self, new_len: NonZeroUsize) {
+        self.0.truncate(new_len.get())
+    }
+
+    /// Inserts a character into this string at the byte position `idx`.
+    /// # Panics
+    /// If `idx` is larger than the string's length, or if it does not lie on a [`char`] boundary.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.0.insert(idx, ch)
+    }
+
+    /// Inserts a string slice into this string at the byte position `idx`.
+    /// # Panics
+    /// If `idx` is larger than the string's length, or if it does not lie on a [`char`] boundary.
+    #[inline]
+    #[track_caller]
+    pub fn insert_str(&mut self, idx: usize, string: &str) {
+        self.0.insert_str(idx, string)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<NonEmptyString> for String {
+    fn from(s: NonEmptyString) -> Self {
+        s.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<char> for NonEmptyString {
+    #[inline]
+    fn from(ch: char) -> Self {
+        Self::new(ch)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn from(s: &NonEmptyStr) -> Self {
+        Self(String::from(s.as_str()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<Box<NonEmptyStr>> for NonEmptyString {
+    #[inline]
+    fn from(s: Box<NonEmptyStr>) -> Self {
+        Self(s.into_boxed_str().into_string())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyContainerError<String>;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyContainerError(value))
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        NonEmptyStr::new(value).map(Self::from).ok_or(EmptyError)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for NonEmptyString {
+    type Err = EmptyError;
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ops::Deref for NonEmptyString {
+    type Target = NonEmptyStr;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_ne_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ops::DerefMut for NonEmptyString {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_ne_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<[u8]> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn as_ref(&self) -> &NonEmptyStr {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<str> for NonEmptyString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Borrow<NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn borrow(&self) -> &NonEmptyStr {
+        self
+    }
+}
+#[cfg(feature = "alloc")]
+impl BorrowMut<NonEmptyStr> for NonEmptyString {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut NonEmptyStr {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToOwned for NonEmptyStr {
+    type Owned = NonEmptyString;
+    #[inline]
+    fn to_owned(&self) -> NonEmptyString {
+        NonEmptyString::from(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clone for Box<NonEmptyStr> {
+    #[inline]
+    fn clone(&self) -> Self {
+        NonEmptyString::from(&**self).into_boxed_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+#[cfg(feature = "alloc")]
+impl PartialEq<&str> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+#[cfg(feature = "alloc")]
+impl PartialEq<String> for NonEmptyString {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.0 == *other
+    }
+}
+#[cfg(feature = "alloc")]
+impl PartialEq<NonEmptyString> for str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        self == other.as_str()
+    }
+}
+#[cfg(feature = "alloc")]
+impl PartialEq<NonEmptyString> for &str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        *self == other.as_str()
+    }
+}
+#[cfg(feature = "alloc")]
+impl PartialEq<NonEmptyString> for String {
+    #[inline]
+    fn eq(&self, other: &NonEmptyString) -> bool {
+        *self == other.0
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl Serialize for NonEmptyString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de> Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(String::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty string"))
+    }
+}
+
+/// Wrapper for a string slice that is guaranteed to have `len > 0`. This allows
+/// some operations to be infallible, such as [`first_char`](#method.first_char)
+/// or [`last_char`](#method.last_char).
+///
+/// This invariant may be relied upon in unsafe code.
+///
+/// `NonEmptyStr` dereferences to a `str`, so all of the familiar methods are still available.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyStr(str);
+
+impl NonEmptyStr {
+    /// Creates a new `NonEmptyStr` without checking the length.
+    /// # Safety
+    /// Ensure that the input string is not empty.
+    #[inline]
+    pub const unsafe fn unchecked(s: &str) -> &Self {
+        debug_assert!(!s.is_empty());
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the references like this.
+        &*(s as *const str as *const Self)
+    }
+    /// Creates a new `NonEmptyStr` without checking the length.
+    /// # Safety
+    /// Ensure that the input string is not empty.
+    #[inline]
+    pub unsafe fn unchecked_mut(s: &mut str) -> &mut Self {
+        debug_assert!(!s.is_empty());
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the references like this.
+        &mut *(s as *mut str as *mut Self)
+    }
+    /// Creates a boxed `NonEmptyStr` without checking the length.
+    /// # Safety
+    /// Ensure that the input string is not empty.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub unsafe fn unchecked_boxed(s: Box<str>) -> Box<Self> {
+        debug_assert!(!s.is_empty());
+        // SAFETY: This type is `repr(transparent)`, so we can safely
+        // cast the pointers like this.
+        let ptr = Box::into_raw(s) as *mut Self;
+        Box::from_raw(ptr)
+    }
+
+    /// Creates a new `NonEmptyStr` from a primitive string slice. Returns [`None`] if the string is empty.
+    /// # Examples
+    /// ```
+    /// # use non_empty_vec::NonEmptyStr;
+    /// assert!(NonEmptyStr::new("a").is_some());
+    /// assert!(NonEmptyStr::new("").is_none());
+    /// ```
+    #[inline]
+    pub const fn new(s: &str) -> Option<&Self> {
+        if !s.is_empty() {
+            // SAFETY: We just checked that it's not empty.
+            unsafe { Some(Self::unchecked(s)) }
+        } else {
+            None
+        }
+    }
+    /// Creates a new `NonEmptyStr` from a primitive string slice. Returns [`None`] if the string is empty.
+    #[inline]
+    pub fn new_mut(s: &mut str) -> Option<&mut Self> {
+        if !s.is_empty() {
+            // SAFETY: We just checked that it's not empty.
+            unsafe { Some(Self::unchecked_mut(s)) }
+        } else {
+            None
+        }
+    }
+
+    /// Converts this `NonEmptyStr` into a primitive string slice.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// Converts this `NonEmptyStr` into a primitive string slice.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        &mut self.0
+    }
+    /// Converts this `NonEmptyStr` into a primitive boxed string slice.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn into_boxed_str(self: Box<Self>) -> Box<str> {
+        // SAFETY: This type is `repr(transparent)`, so we can
+        // safely cast the pointer like this.
+        let ptr = Box::into_raw(self) as *mut str;
+        unsafe { Box::from_raw(ptr) }
+    }
+
+    /// Returns the length of this string in bytes.
+    #[inline]
+    pub const fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+    /// Returns `false`.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the first character of this string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyStr;
+    /// let s = NonEmptyStr::new("héllo").unwrap();
+    /// assert_eq!(s.first_char(), 'h');
+    /// ```
+    #[inline]
+    pub fn first_char(&self) -> char {
+        self.split_first_char().0
+    }
+    /// Returns the last character of this string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyStr;
+    /// let s = NonEmptyStr::new("wörld").unwrap();
+    /// assert_eq!(s.last_char(), 'd');
+    /// ```
+    #[inline]
+    pub fn last_char(&self) -> char {
+        self.split_last_char().0
+    }
+
+    /// Splits this string into its first character and the rest of the string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyStr;
+    /// let s = NonEmptyStr::new("ßig").unwrap();
+    /// assert_eq!(s.split_first_char(), ('ß', "ig"));
+    /// ```
+    #[inline]
+    pub fn split_first_char(&self) -> (char, &str) {
+        let mut chars = self.0.chars();
+        match chars.next() {
+            Some(first) => (first, chars.as_str()),
+            // SAFETY: This instance is non-empty, so it has at least one character.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+    /// Splits this string into its last character and the rest of the string.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::NonEmptyStr;
+    /// let s = NonEmptyStr::new("ab€").unwrap();
+    /// assert_eq!(s.split_last_char(), ('€', "ab"));
+    /// ```
+    #[inline]
+    pub fn split_last_char(&self) -> (char, &str) {
+        let mut chars = self.0.chars();
+        match chars.next_back() {
+            Some(last) => (last, chars.as_str()),
+            // SAFETY: This instance is non-empty, so it has at least one character.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a NonEmptyStr {
+    type Error = EmptyError;
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        NonEmptyStr::new(value).ok_or(EmptyError)
+    }
+}
+impl<'a> TryFrom<&'a mut str> for &'a mut NonEmptyStr {
+    type Error = EmptyError;
+    fn try_from(value: &'a mut str) -> Result<Self, Self::Error> {
+        NonEmptyStr::new_mut(value).ok_or(EmptyError)
+    }
+}
+#[cfg(feature = "alloc")]
+impl TryFrom<Box<str>> for Box<NonEmptyStr> {
+    type Error = EmptyContainerError<Box<str>>;
+    fn try_from(value: Box<str>) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            Err(EmptyContainerError(value))
+        } else {
+            // SAFETY: We just checked that it's not empty.
+            Ok(unsafe { NonEmptyStr::unchecked_boxed(value) })
+        }
+    }
+}
+
+impl ops::Deref for NonEmptyStr {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+impl ops::DerefMut for NonEmptyStr {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+impl AsRef<[u8]> for NonEmptyStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Debug for NonEmptyStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+impl fmt::Display for NonEmptyStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq<str> for NonEmptyStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+impl PartialEq<&str> for NonEmptyStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == **other
+    }
+}
+impl PartialEq<NonEmptyStr> for str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        *self == other.0
+    }
+}
+impl PartialEq<NonEmptyStr> for &str {
+    #[inline]
+    fn eq(&self, other: &NonEmptyStr) -> bool {
+        **self == other.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for NonEmptyStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize(serializer)
+    }
+}
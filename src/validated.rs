@@ -0,0 +1,193 @@
+//! A result type that accumulates errors into a [`NonEmpty`] vector.
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+use crate::NonEmpty;
+
+/// Either a successful value, or every error found while producing it.
+///
+/// Unlike [`Result`], combining two failed `Validated`s keeps the errors of both,
+/// so that all problems can be reported at once.
+/// # Example
+/// ```
+/// # use non_empty_vec::{ne_vec, Validated};
+/// fn check_name(name: &str) -> Validated<&str, &'static str> {
+///     if name.is_empty() {
+///         Validated::Err(ne_vec!["name is empty"])
+///     } else {
+///         Validated::Ok(name)
+///     }
+/// }
+///
+/// fn check_age(age: u32) -> Validated<u32, &'static str> {
+///     if age < 18 {
+///         Validated::Err(ne_vec!["too young"])
+///     } else {
+///         Validated::Ok(age)
+///     }
+/// }
+///
+/// assert_eq!(check_name("Ann").zip(check_age(30)), Validated::Ok(("Ann", 30)));
+/// assert_eq!(
+///     check_name("").zip(check_age(3)),
+///     Validated::Err(ne_vec!["name is empty", "too young"]),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validated<T, E> {
+    /// Contains the success value.
+    Ok(T),
+    /// Contains all the errors.
+    Err(NonEmpty<E>),
+}
+
+impl<T, E> Validated<T, E> {
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Validated::Ok(_))
+    }
+
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        matches!(self, Validated::Err(_))
+    }
+
+    /// Converts into an [`Option`] of the success value, discarding the errors.
+    #[inline]
+    pub fn ok(self) -> Option<T> {
+        match self {
+            Validated::Ok(t) => Some(t),
+            Validated::Err(_) => None,
+        }
+    }
+
+    /// Converts into an [`Option`] of the errors, discarding the success value.
+    #[inline]
+    pub fn err(self) -> Option<NonEmpty<E>> {
+        match self {
+            Validated::Ok(_) => None,
+            Validated::Err(errors) => Some(errors),
+        }
+    }
+
+    /// Converts into a [`Result`].
+    #[inline]
+    pub fn into_result(self) -> Result<T, NonEmpty<E>> {
+        match self {
+            Validated::Ok(t) => Ok(t),
+            Validated::Err(errors) => Err(errors),
+        }
+    }
+
+    /// Maps the success value, leaving the errors untouched.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Validated<U, E>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Validated::Ok(t) => Validated::Ok(f(t)),
+            Validated::Err(errors) => Validated::Err(errors),
+        }
+    }
+
+    /// Maps each of the errors, leaving the success value untouched.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, Validated};
+    /// let v: Validated<(), i32> = Validated::Err(ne_vec![1, 2]);
+    /// assert_eq!(v.map_err(|e| e * 10), Validated::Err(ne_vec![10, 20]));
+    /// ```
+    #[inline]
+    pub fn map_err<F, O>(self, op: O) -> Validated<T, F>
+    where
+        O: FnMut(E) -> F,
+    {
+        match self {
+            Validated::Ok(t) => Validated::Ok(t),
+            Validated::Err(errors) => Validated::Err(errors.map(op)),
+        }
+    }
+
+    /// Returns `other` if both are successful, otherwise the errors of both, in order.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, Validated};
+    /// let ok: Validated<i32, &str> = Validated::Ok(1);
+    /// let a: Validated<i32, &str> = Validated::Err(ne_vec!["a"]);
+    /// let b: Validated<i32, &str> = Validated::Err(ne_vec!["b"]);
+    /// assert_eq!(ok.clone().and(Validated::Ok(2)), Validated::Ok(2));
+    /// assert_eq!(ok.and(b.clone()), b);
+    /// assert_eq!(a.and(b), Validated::Err(ne_vec!["a", "b"]));
+    /// ```
+    #[inline]
+    pub fn and<U>(self, other: Validated<U, E>) -> Validated<U, E> {
+        self.zip(other).map(|(_, u)| u)
+    }
+
+    /// Pairs up the success values if both are successful, otherwise returns the errors of both, in order.
+    pub fn zip<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+        match (self, other) {
+            (Validated::Ok(t), Validated::Ok(u)) => Validated::Ok((t, u)),
+            (Validated::Ok(_), Validated::Err(errors))
+            | (Validated::Err(errors), Validated::Ok(_)) => Validated::Err(errors),
+            (Validated::Err(mut errors), Validated::Err(more)) => {
+                errors.0.extend(more.0);
+                Validated::Err(errors)
+            }
+        }
+    }
+}
+
+impl<T, E> From<Result<T, NonEmpty<E>>> for Validated<T, E> {
+    #[inline]
+    fn from(result: Result<T, NonEmpty<E>>) -> Self {
+        match result {
+            Ok(t) => Validated::Ok(t),
+            Err(errors) => Validated::Err(errors),
+        }
+    }
+}
+
+impl<T, E> From<Validated<T, E>> for Result<T, NonEmpty<E>> {
+    #[inline]
+    fn from(v: Validated<T, E>) -> Self {
+        v.into_result()
+    }
+}
+
+/// Collects the success values into `C` if there are no errors, otherwise collects every error.
+/// # Example
+/// ```
+/// # use non_empty_vec::{ne_vec, Validated};
+/// let v: Validated<Vec<i32>, _> = ["1", "2"].iter().map(|s| s.parse::<i32>()).collect();
+/// assert_eq!(v, Validated::Ok(vec![1, 2]));
+///
+/// let v: Validated<Vec<i32>, _> = ["x", "2", "y"].iter().map(|s| s.parse::<i32>()).collect();
+/// assert_eq!(v.err().unwrap().len().get(), 2);
+/// ```
+impl<T, E, C> FromIterator<Result<T, E>> for Validated<C, E>
+where
+    C: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Self {
+        let mut errors = Vec::new();
+        let oks = iter
+            .into_iter()
+            .filter_map(|result| match result {
+                Ok(t) => Some(t),
+                Err(e) => {
+                    errors.push(e);
+                    None
+                }
+            })
+            .collect();
+        if errors.is_empty() {
+            Validated::Ok(oks)
+        } else {
+            // SAFETY: `errors` was just checked to be non-empty.
+            Validated::Err(unsafe { NonEmpty::new_unchecked(errors) })
+        }
+    }
+}
@@ -0,0 +1,350 @@
+//! A [`VecDeque`] that is guaranteed to contain at least one element.
+
+use alloc::collections::vec_deque::{self, VecDeque};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::num::NonZeroUsize;
+use core::ops;
+
+use crate::{EmptyContainerError, FromNonEmptyIterator, NonEmpty, NonEmptyIterator, NonEmptySlice};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Non empty double-ended queue, ensure non empty by construction.
+/// Inherits `VecDeque`'s methods through `Deref` trait, not implement `DerefMut`.
+/// Overridden these methods:
+/// * `len` returns `NonZeroUsize` and `is_empty` always returns `false`.
+/// * `front(_mut)` and `back(_mut)` don't return `Option`.
+/// * `pop_front` and `pop_back` return `None` if there is only one element in it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmptyVecDeque<T>(VecDeque<T>);
+
+impl<T> NonEmptyVecDeque<T> {
+    #[inline]
+    pub fn new(v: T) -> Self {
+        let mut deque = VecDeque::with_capacity(1);
+        deque.push_back(v);
+        Self(deque)
+    }
+
+    /// Constructs a non-empty deque without checking its size.
+    ///
+    /// # Safety
+    /// `deque` should not be empty.
+    #[inline]
+    pub unsafe fn new_unchecked(deque: VecDeque<T>) -> Self {
+        debug_assert!(!deque.is_empty());
+        Self(deque)
+    }
+
+    #[inline]
+    pub fn as_vec_deque(&self) -> &VecDeque<T> {
+        &self.0
+    }
+
+    #[inline]
+    pub fn len(&self) -> NonZeroUsize {
+        unsafe { NonZeroUsize::new_unchecked(self.0.len()) }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns a reference to the front element of the deque.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyVecDeque};
+    /// let mut d = NonEmptyVecDeque::from(ne_vec![1, 2]);
+    /// d.push_front(0);
+    /// assert_eq!(d.front(), &0);
+    /// ```
+    #[inline]
+    pub fn front(&self) -> &T {
+        match self.0.front() {
+            Some(front) => front,
+            // SAFETY: This deque is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+    #[inline]
+    pub fn front_mut(&mut self) -> &mut T {
+        match self.0.front_mut() {
+            Some(front) => front,
+            // SAFETY: This deque is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    /// Returns a reference to the back element of the deque.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyVecDeque};
+    /// let mut d = NonEmptyVecDeque::from(ne_vec![1, 2]);
+    /// d.push_back(3);
+    /// assert_eq!(d.back(), &3);
+    /// ```
+    #[inline]
+    pub fn back(&self) -> &T {
+        match self.0.back() {
+            Some(back) => back,
+            // SAFETY: This deque is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+    #[inline]
+    pub fn back_mut(&mut self) -> &mut T {
+        match self.0.back_mut() {
+            Some(back) => back,
+            // SAFETY: This deque is non-empty.
+            None => unsafe { unreachable_unchecked!() },
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.0.get_mut(index)
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> vec_deque::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        self.0.as_mut_slices()
+    }
+
+    #[inline]
+    pub fn push_front(&mut self, v: T) {
+        self.0.push_front(v)
+    }
+
+    #[inline]
+    pub fn push_back(&mut self, v: T) {
+        self.0.push_back(v)
+    }
+
+    /// Removes the front element and returns it.
+    /// Returns `None` if there is only one element in the deque.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyVecDeque};
+    /// let mut d = NonEmptyVecDeque::from(ne_vec![1, 2]);
+    /// assert_eq!(d.pop_front(), Some(1));
+    /// assert_eq!(d.pop_front(), None);
+    /// assert_eq!(d.front(), &2);
+    /// ```
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_front()
+        }
+    }
+
+    /// Removes the back element and returns it.
+    /// Returns `None` if there is only one element in the deque.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.0.len() <= 1 {
+            None
+        } else {
+            self.0.pop_back()
+        }
+    }
+
+    /// Inserts an element at position `index`, shifting the following elements towards the back.
+    /// # Panics
+    /// If `index > len`.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.0.insert(index, value)
+    }
+
+    #[inline]
+    #[track_caller]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j)
+    }
+
+    #[inline]
+    pub fn truncate(&mut self, len: NonZeroUsize) {
+        self.0.truncate(len.get())
+    }
+
+    /// Rotates the deque `n` places to the left, so that the element at index `n` becomes the front.
+    /// # Panics
+    /// If `n > len`.
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyVecDeque};
+    /// let mut d = NonEmptyVecDeque::from(ne_vec![1, 2, 3]);
+    /// d.rotate_left(1);
+    /// assert_eq!(d.front(), &2);
+    /// d.rotate_right(2);
+    /// assert_eq!(d.front(), &3);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn rotate_left(&mut self, n: usize) {
+        self.0.rotate_left(n)
+    }
+
+    /// Rotates the deque `n` places to the right, so that the element at index `len - n` becomes the front.
+    /// # Panics
+    /// If `n > len`.
+    #[inline]
+    #[track_caller]
+    pub fn rotate_right(&mut self, n: usize) {
+        self.0.rotate_right(n)
+    }
+
+    /// Rearranges the elements of the deque so they are stored contiguously, and returns them as a
+    /// [non-empty slice](NonEmptySlice).
+    /// # Example
+    /// ```
+    /// # use non_empty_vec::{ne_vec, NonEmptyVecDeque};
+    /// let mut d = NonEmptyVecDeque::from(ne_vec![2, 3]);
+    /// d.push_front(1);
+    /// let s = d.make_contiguous();
+    /// s.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(s, &[3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &mut NonEmptySlice<T> {
+        // SAFETY: This deque is non-empty.
+        unsafe { NonEmptySlice::unchecked_mut(self.0.make_contiguous()) }
+    }
+}
+
+impl<T> From<NonEmpty<T>> for NonEmptyVecDeque<T> {
+    /// Converts the vector into a deque, reusing its buffer.
+    #[inline]
+    fn from(v: NonEmpty<T>) -> Self {
+        Self(VecDeque::from(Vec::from(v)))
+    }
+}
+
+impl<T> From<NonEmptyVecDeque<T>> for NonEmpty<T> {
+    /// Converts the deque into a vector, reusing its buffer.
+    ///
+    /// The elements are moved if they are not already stored contiguously from the start of the buffer.
+    #[inline]
+    fn from(d: NonEmptyVecDeque<T>) -> Self {
+        // SAFETY: The deque is non-empty.
+        unsafe { NonEmpty::new_unchecked(Vec::from(d.0)) }
+    }
+}
+
+impl<T> From<NonEmptyVecDeque<T>> for VecDeque<T> {
+    #[inline]
+    fn from(d: NonEmptyVecDeque<T>) -> Self {
+        d.0
+    }
+}
+
+impl<T> TryFrom<VecDeque<T>> for NonEmptyVecDeque<T> {
+    type Error = EmptyContainerError<VecDeque<T>>;
+    fn try_from(deque: VecDeque<T>) -> Result<Self, Self::Error> {
+        if deque.is_empty() {
+            Err(EmptyContainerError(deque))
+        } else {
+            Ok(Self(deque))
+        }
+    }
+}
+
+impl<T> FromNonEmptyIterator<T> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn from_ne_iter<I>(iter: I) -> Self
+    where
+        I: NonEmptyIterator<Item = T>,
+    {
+        let d = VecDeque::from_iter(iter);
+        // SAFETY: Non-empty iterators always yield at least one item.
+        unsafe { Self::new_unchecked(d) }
+    }
+}
+
+impl<T> ops::Deref for NonEmptyVecDeque<T> {
+    type Target = VecDeque<T>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<VecDeque<T>> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn as_ref(&self) -> &VecDeque<T> {
+        &self.0
+    }
+}
+
+impl<T> ops::Index<usize> for NonEmptyVecDeque<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+impl<T> ops::IndexMut<usize> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T> Extend<T> for NonEmptyVecDeque<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for NonEmptyVecDeque<T> {
+    type Item = T;
+    type IntoIter = vec_deque::IntoIter<T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a NonEmptyVecDeque<T> {
+    type Item = &'a T;
+    type IntoIter = vec_deque::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+impl<'a, T> IntoIterator for &'a mut NonEmptyVecDeque<T> {
+    type Item = &'a mut T;
+    type IntoIter = vec_deque::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for NonEmptyVecDeque<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyVecDeque<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(<VecDeque<T>>::deserialize(deserializer)?)
+            .map_err(|_| D::Error::custom("empty deque"))
+    }
+}